@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use common::Answer;
+
+/// Known-good answers for the local inputs, one `<day> <part> <answer>` line each.
+/// Newlines in text answers are stored as `\n`.
+#[derive(Default)]
+pub struct Answers {
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => { out.push('\n'); chars.next(); }
+            ('\\', Some('\\')) => { out.push('\\'); chars.next(); }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+impl Answers {
+    /// Loads an answers file. A missing file is treated as empty.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Answers> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("line {}: expected `<day> <part> <answer>`", index + 1));
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next().and_then(|x| x.parse::<u32>().ok()).ok_or_else(invalid)?;
+            let part = fields.next().and_then(|x| x.parse::<u32>().ok()).ok_or_else(invalid)?;
+            let answer = fields.next().ok_or_else(invalid)?;
+            answers.insert((day, part), unescape(answer));
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut contents = String::from("# aoc answers: <day> <part> <answer>\n");
+        for ((day, part), answer) in &self.answers {
+            contents += &format!("{} {} {}\n", day, part, escape(answer));
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u32, part: u32, answer: &Answer) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}-{}.txt", name, std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn escapes_round_trip() {
+        for text in &["plain", "back\\slash", "two\nlines", "literal \\n", "\\\n\\\\n", "trailing\\"] {
+            assert_eq!(unescape(&escape(text)), *text);
+            assert!(!escape(text).contains('\n'));
+        }
+        assert_eq!(escape("a\\nb\nc"), "a\\\\nb\\nc");
+    }
+
+    #[test]
+    fn load_skips_comments() {
+        let path = temp_file("comments", "# header\n\n1 1 3412\n# 1 2 ignored\n8 2 #..#\\n.##.\n");
+        let answers = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(answers.get(1, 1), Some("3412"));
+        assert_eq!(answers.get(1, 2), None);
+        assert_eq!(answers.get(8, 2), Some("#..#\n.##."));
+    }
+
+    #[test]
+    fn load_rejects_malformed_lines() {
+        for (name, contents) in &[("day", "x 1 2\n"), ("part", "1 one 2\n"), ("answer", "# ok\n1 1\n")] {
+            let path = temp_file(name, contents);
+            let error = Answers::load(&path).err().unwrap();
+            fs::remove_file(&path).unwrap();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+        let path = temp_file("line", "1 1 5\n\n2 x 5\n");
+        let error = Answers::load(&path).err().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(error.to_string(), "line 3: expected `<day> <part> <answer>`");
+    }
+
+    #[test]
+    fn missing_file_is_empty() {
+        let answers = Answers::load(std::env::temp_dir().join("aoc-answers-missing")).unwrap();
+        assert_eq!(answers.get(1, 1), None);
+    }
+
+    #[test]
+    fn save_then_load() {
+        let mut answers = Answers::default();
+        answers.set(1, 1, &Answer::Int(-7));
+        answers.set(8, 2, &Answer::Text("a\\nb\nc d".to_string()));
+        let path = temp_file("saved", "");
+        answers.save(&path).unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.answers, answers.answers);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use common::{Day, Result};
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Every sample taken of one stage of one day.
+pub struct Timing {
+    pub day: u32,
+    pub stage: &'static str,
+    pub samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        *self.samples.iter().min().unwrap()
+    }
+
+    pub fn max(&self) -> Duration {
+        *self.samples.iter().max().unwrap()
+    }
+}
+
+/// Times parsing and both parts separately, `runs` times each. Each run solves from its own parse.
+pub fn bench_day(day: &Day, input: &str, runs: usize) -> Result<Vec<Timing>> {
+    let mut timings: Vec<Timing> = STAGES.iter().map(|&stage| Timing {
+        day: day.day,
+        stage,
+        samples: Vec::new(),
+    }).collect();
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = day.parse(input)?;
+        timings[0].samples.push(start.elapsed());
+
+        for part in 1..=2 {
+            let start = Instant::now();
+            day.solve(part, &*parsed)?;
+            timings[part as usize].samples.push(start.elapsed());
+        }
+    }
+    Ok(timings)
+}
+
+/// Median times from an earlier run, one `<day> <stage> <nanoseconds>` line each.
+#[derive(Default)]
+pub struct Baseline {
+    medians: BTreeMap<(u32, String), Duration>,
+}
+
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Baseline> {
+        let mut medians = BTreeMap::new();
+        for (index, line) in fs::read_to_string(path)?.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("line {}: expected `<day> <stage> <nanoseconds>`", index + 1));
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                return Err(invalid());
+            }
+            let day = fields[0].parse::<u32>().map_err(|_| invalid())?;
+            let nanos = fields[2].parse::<u64>().map_err(|_| invalid())?;
+            medians.insert((day, fields[1].to_string()), Duration::from_nanos(nanos));
+        }
+        Ok(Baseline { medians })
+    }
+
+    pub fn save(timings: &[Timing], path: impl AsRef<Path>) -> io::Result<()> {
+        let mut contents = String::from("# aoc bench baseline: <day> <stage> <median nanoseconds>\n");
+        for timing in timings {
+            contents += &format!("{} {} {}\n", timing.day, timing.stage, timing.median().as_nanos());
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, day: u32, stage: &str) -> Option<Duration> {
+        self.medians.get(&(day, stage.to_string())).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn timing(day: u32, stage: &'static str, millis: &[u64]) -> Timing {
+        Timing { day, stage, samples: millis.iter().map(|&x| Duration::from_millis(x)).collect() }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-baseline-{}-{}.txt", name, std::process::id()))
+    }
+
+    #[test]
+    fn medians() {
+        assert_eq!(timing(1, "parse", &[7]).median(), Duration::from_millis(7));
+        assert_eq!(timing(1, "parse", &[9, 1, 4]).median(), Duration::from_millis(4));
+        assert_eq!(timing(1, "parse", &[8, 1, 4, 2]).median(), Duration::from_millis(3));
+        assert_eq!(timing(1, "parse", &[5, 2]).median(), Duration::from_micros(3500));
+
+        let sample = timing(1, "parse", &[8, 1, 4, 2]);
+        assert_eq!((sample.min(), sample.max()), (Duration::from_millis(1), Duration::from_millis(8)));
+    }
+
+    #[test]
+    fn save_then_load() {
+        let path = temp_path("saved");
+        Baseline::save(&[timing(3, "part1", &[2, 4]), timing(12, "parse", &[5])], &path).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(baseline.get(3, "part1"), Some(Duration::from_millis(3)));
+        assert_eq!(baseline.get(12, "parse"), Some(Duration::from_millis(5)));
+        assert_eq!(baseline.get(3, "part2"), None);
+    }
+
+    #[test]
+    fn missing_baseline() {
+        let error = Baseline::load(temp_path("missing")).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn malformed_baseline() {
+        for (name, contents) in &[("fields", "1 parse\n"), ("day", "one parse 5\n"), ("nanos", "# ok\n1 parse 5ms\n")] {
+            let path = temp_path(name);
+            fs::write(&path, contents).unwrap();
+            let error = Baseline::load(&path).err().unwrap();
+            fs::remove_file(&path).unwrap();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::any::Any;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+use common::{default_input, fail, Answer, Day};
+use common::image::Palette;
+
+use crate::answers::{escape, Answers};
+use crate::bench::{bench_day, Baseline};
+use crate::report::{Format, Outcome, Reporter};
+
+mod answers;
+mod bench;
+mod report;
+
+fn days() -> Vec<Day> {
+    vec![
+        Day::new::<day01::Day01>(),
+        Day::new::<day02::Day02>(),
+        Day::new::<day03::Day03>(),
+        Day::new::<day04::Day04>(),
+        Day::new::<day05::Day05>(),
+        Day::new::<day06::Day06>(),
+        Day::new::<day07::Day07>(),
+        Day::new::<day08::Day08>(),
+        Day::new::<day09::Day09>(),
+        Day::new::<day10::Day10>(),
+        Day::new::<day11::Day11>(),
+        Day::new::<day12::Day12>(),
+        Day::new::<day13::Day13>(),
+        Day::new::<day14::Day14>(),
+        Day::new::<day15::Day15>(),
+        Day::new::<day16::Day16>(),
+    ]
+}
+
+const USAGE: &str = "usage: aoc list
+       aoc run <day> [--part 1|2] [--input path] [--format json|csv|text]
+               [--image path.ppm|pbm|png] [--scale n] [--palette rrggbb,...]
+       aoc run --all [--format json|csv|text]
+       aoc verify [--answers path] [--accept]
+       aoc bench [<day>] [--runs n] [--save path] [--baseline path]";
+
+/// Runs the requested parts of one day, reporting a failure for each if the input is unusable.
+/// Returns the parsed input, if it could be.
+fn run_day(day: &Day, parts: &[u32], path: &str, reporter: &mut Reporter<impl Write>) -> Option<Box<dyn Any>> {
+    let input = fs::read_to_string(path).map_err(|e| e.into()).and_then(|input| day.parse(&input));
+    for &part in parts {
+        let outcome = match &input {
+            Ok(input) => {
+                let start = Instant::now();
+                match day.solve(part, &**input) {
+                    Ok(answer) => Outcome::Solved(answer, start.elapsed()),
+                    Err(e) => Outcome::Failed(e.to_string()),
+                }
+            }
+            Err(e) => Outcome::Failed(format!("{}: {}", path, e)),
+        };
+        reporter.record(day.day, part, &outcome).unwrap_or_else(|e| fail(&e.to_string()));
+    }
+    input.ok()
+}
+
+/// Where and how to draw the pictures of the parts that have one.
+struct ImageOptions {
+    path: PathBuf,
+    scale: usize,
+    palette: Option<Palette>,
+}
+
+/// Saves each part's picture. With more than one part, `-partN` is added to the file name.
+fn save_pictures(day: &Day, parts: &[u32], input: &dyn Any, options: &ImageOptions) -> common::Result<()> {
+    for &part in parts {
+        let mut picture = match day.picture(part, input) {
+            Some(picture) => picture,
+            None => {
+                eprintln!("day {} part {}: no picture", day.day, part);
+                continue;
+            }
+        };
+        if let Some(palette) = &options.palette {
+            picture.palette = palette.clone();
+        }
+
+        let mut path = options.path.clone();
+        if parts.len() > 1 {
+            let stem = path.file_stem().and_then(|x| x.to_str()).unwrap_or("").to_string();
+            let extension = path.extension().and_then(|x| x.to_str()).unwrap_or("").to_string();
+            path.set_file_name(format!("{}-part{}.{}", stem, part, extension));
+        }
+        picture.bitmap(options.scale).save(&path)?;
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) {
+    let days = days();
+    let mut day = None;
+    let mut all = false;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut format = Format::Text;
+    let mut image = None;
+    let mut scale = 4;
+    let mut palette = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--part" => parts = match args.next().map(String::as_str) {
+                Some("1") => vec![1],
+                Some("2") => vec![2],
+                _ => fail("--part must be 1 or 2"),
+            },
+            "--input" => input = Some(args.next().unwrap_or_else(|| fail("--input needs a path")).clone()),
+            "--format" => format = args.next().and_then(|x| Format::parse(x)).unwrap_or_else(|| fail("--format must be json, csv or text")),
+            "--image" => image = Some(PathBuf::from(args.next().unwrap_or_else(|| fail("--image needs a path")))),
+            "--scale" => scale = args.next().and_then(|x| x.parse::<usize>().ok()).filter(|x| *x > 0)
+                .unwrap_or_else(|| fail("--scale must be a positive number")),
+            "--palette" => palette = Some(Palette::parse(args.next().unwrap_or_else(|| fail("--palette needs colours")))
+                .unwrap_or_else(|e| fail(&e.to_string()))),
+            _ => day = Some(arg.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid day `{}`", arg)))),
+        }
+    }
+
+    if all {
+        if day.is_some() || input.is_some() {
+            fail("--all runs every day against its own input");
+        }
+        if image.is_some() {
+            fail("--image needs a single day");
+        }
+        let mut reporter = Reporter::new(format, io::stdout()).unwrap_or_else(|e| fail(&e.to_string()));
+        for day in &days {
+            let path = default_input(day.day);
+            // Days without an input are left out rather than reported as failures.
+            if let Err(e) = fs::metadata(&path) {
+                eprintln!("day {}: skipped, {}: {}", day.day, path, e);
+                continue;
+            }
+            run_day(day, &parts, &path, &mut reporter);
+        }
+        if reporter.finish().unwrap_or_else(|e| fail(&e.to_string())) > 0 {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let day = day.unwrap_or_else(|| fail(USAGE));
+    let day = days.iter().find(|x| x.day == day).unwrap_or_else(|| fail(&format!("no solution for day {}", day)));
+    let path = input.unwrap_or_else(|| default_input(day.day));
+    let mut reporter = Reporter::new(format, io::stdout()).unwrap_or_else(|e| fail(&e.to_string()));
+    let input = run_day(day, &parts, &path, &mut reporter);
+    if reporter.finish().unwrap_or_else(|e| fail(&e.to_string())) > 0 {
+        process::exit(1);
+    }
+
+    if let (Some(image), Some(input)) = (image, input) {
+        let options = ImageOptions { path: image, scale, palette };
+        save_pictures(day, &parts, &*input, &options).unwrap_or_else(|e| fail(&e.to_string()));
+    }
+}
+
+/// Reruns every day against its input and compares with the recorded answers. `--accept`
+/// records the current answer for any part that has none yet; existing entries are never changed.
+fn verify(args: &[String]) {
+    let mut path = "answers.txt".to_string();
+    let mut accept = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => path = args.next().unwrap_or_else(|| fail("--answers needs a path")).clone(),
+            "--accept" => accept = true,
+            _ => fail(USAGE),
+        }
+    }
+
+    let mut answers = Answers::load(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    let (mut matched, mut mismatched, mut unrecorded) = (0, 0, 0);
+    for day in &days() {
+        let input_path = default_input(day.day);
+        let input = match fs::read_to_string(&input_path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {}: skipped, {}: {}", day.day, input_path, e);
+                continue;
+            }
+        };
+        let input = match day.parse(&input) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("day {}: FAILED to parse {}: {}", day.day, input_path, e);
+                mismatched += 2;
+                continue;
+            }
+        };
+
+        for part in 1..=2 {
+            let answer = day.solve(part, &*input).unwrap_or_else(|e| fail(&e.to_string()));
+            let actual = answer.to_string();
+            match answers.get(day.day, part) {
+                Some(expected) if expected == actual => matched += 1,
+                Some(expected) => {
+                    println!("day {} part {}: MISMATCH, expected {}, got {}", day.day, part, escape(expected), escape(&actual));
+                    mismatched += 1;
+                }
+                None => {
+                    println!("day {} part {}: no recorded answer, got {}", day.day, part, escape(&actual));
+                    if accept && answer != Answer::Unsolved {
+                        answers.set(day.day, part, &answer);
+                    }
+                    unrecorded += 1;
+                }
+            }
+        }
+    }
+
+    if accept {
+        answers.save(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    }
+    println!("{} matched, {} mismatched, {} unrecorded", matched, mismatched, unrecorded);
+    if mismatched > 0 {
+        process::exit(1);
+    }
+}
+
+/// Times every day (or just one) and prints the median and range of each stage, optionally
+/// against the medians saved by an earlier `--save`.
+fn bench(args: &[String]) {
+    let mut day = None;
+    let mut runs = 10;
+    let mut save = None;
+    let mut baseline = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().unwrap_or_else(|| fail(&format!("{} needs a value", flag))).clone();
+        match arg.as_str() {
+            "--runs" => runs = value("--runs").parse::<usize>().ok().filter(|x| *x > 0).unwrap_or_else(|| fail("--runs must be a positive number")),
+            "--save" => save = Some(value("--save")),
+            "--baseline" => baseline = Some(value("--baseline")),
+            "--all" => {}
+            _ => day = Some(arg.parse::<u32>().unwrap_or_else(|_| fail(&format!("invalid day `{}`", arg)))),
+        }
+    }
+
+    let baseline = match &baseline {
+        Some(path) => Baseline::load(path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e))),
+        None => Baseline::default(),
+    };
+
+    let days = days();
+    if let Some(day) = day {
+        if !days.iter().any(|x| x.day == day) {
+            fail(&format!("no solution for day {}", day));
+        }
+    }
+
+    let mut timings = Vec::new();
+    let mut failed = 0;
+    for d in days.iter().filter(|d| day.is_none() || day == Some(d.day)) {
+        let path = default_input(d.day);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {}: skipped, {}: {}", d.day, path, e);
+                continue;
+            }
+        };
+        let day_timings = match bench_day(d, &input, runs) {
+            Ok(day_timings) => day_timings,
+            Err(e) => {
+                eprintln!("day {}: FAILED to parse {}: {}", d.day, path, e);
+                failed += 1;
+                continue;
+            }
+        };
+        for timing in &day_timings {
+            let median = timing.median();
+            let change = match baseline.get(timing.day, timing.stage) {
+                Some(old) if old.as_nanos() > 0 => {
+                    let percent = (median.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+                    format!("  {:+.1}% vs {:?}", percent, old)
+                }
+                _ => String::new(),
+            };
+            println!("day {:2} {}  median {:>12?}  range {:?} .. {:?}{}",
+                     timing.day, timing.stage, median, timing.min(), timing.max(), change);
+        }
+        timings.extend(day_timings);
+    }
+
+    if let Some(path) = save {
+        Baseline::save(&timings, &path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    }
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("list") => {
+            for day in days() {
+                println!("{:2}  {}", day.day, day.name);
+            }
+        }
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        _ => fail(USAGE),
+    }
+}
@@ -0,0 +1,168 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use common::Answer;
+use common::escape::{csv_field, json_string};
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// What happened when one part was run.
+pub enum Outcome {
+    Solved(Answer, Duration),
+    /// The input couldn't be read or parsed.
+    Failed(String),
+}
+
+impl Outcome {
+    fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(Answer::Unsolved, _) => "unsolved",
+            Outcome::Solved(..) => "ok",
+            Outcome::Failed(_) => "error",
+        }
+    }
+}
+
+/// Writes results as they come in. JSON is a single array of objects, so `finish` must be
+/// called to close it. Failures in text mode go to stderr instead.
+pub struct Reporter<W: Write> {
+    out: W,
+    format: Format,
+    count: usize,
+    failures: usize,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(format: Format, mut out: W) -> io::Result<Reporter<W>> {
+        if format == Format::Csv {
+            writeln!(out, "day,part,status,answer,time_us")?;
+        }
+        Ok(Reporter { out, format, count: 0, failures: 0 })
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, outcome: &Outcome) -> io::Result<()> {
+        let status = outcome.status();
+        let (answer, time) = match outcome {
+            Outcome::Solved(Answer::Unsolved, time) => (None, Some(time)),
+            Outcome::Solved(answer, time) => (Some(answer.to_string()), Some(time)),
+            Outcome::Failed(_) => (None, None),
+        };
+
+        match self.format {
+            Format::Text => match outcome {
+                // Multi-line answers (rendered letters) start on their own line.
+                Outcome::Solved(Answer::Text(text), _) if text.contains('\n') => writeln!(self.out, "day {} part {}:\n{}", day, part, text)?,
+                Outcome::Solved(answer, _) => writeln!(self.out, "day {} part {}: {}", day, part, answer)?,
+                Outcome::Failed(message) => eprintln!("day {} part {}: {}", day, part, message),
+            },
+            Format::Json => {
+                let mut fields = vec![
+                    format!("\"day\": {}", day),
+                    format!("\"part\": {}", part),
+                    format!("\"status\": \"{}\"", status),
+                    format!("\"answer\": {}", answer.as_deref().map_or("null".to_string(), json_string)),
+                    format!("\"time_us\": {}", time.map_or("null".to_string(), |x| x.as_micros().to_string())),
+                ];
+                if let Outcome::Failed(message) = outcome {
+                    fields.push(format!("\"error\": {}", json_string(message)));
+                }
+                write!(self.out, "{}\n  {{{}}}", if self.count == 0 { "[" } else { "," }, fields.join(", "))?;
+            }
+            Format::Csv => {
+                let answer = match outcome {
+                    Outcome::Failed(message) => message.clone(),
+                    _ => answer.unwrap_or_default(),
+                };
+                writeln!(self.out, "{},{},{},{},{}", day, part, status, csv_field(&answer),
+                         time.map_or(String::new(), |x| x.as_micros().to_string()))?;
+            }
+        }
+        self.count += 1;
+        if let Outcome::Failed(_) = outcome {
+            self.failures += 1;
+        }
+        Ok(())
+    }
+
+    /// Closes the output and returns how many parts failed.
+    pub fn finish(mut self) -> io::Result<usize> {
+        if self.format == Format::Json {
+            writeln!(self.out, "{}", if self.count == 0 { "[]" } else { "\n]" })?;
+        }
+        self.out.flush()?;
+        Ok(self.failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(format: Format, outcomes: &[Outcome]) -> (String, usize) {
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(format, &mut out).unwrap();
+        for (index, outcome) in outcomes.iter().enumerate() {
+            reporter.record(7, index as u32 + 1, outcome).unwrap();
+        }
+        let failures = reporter.finish().unwrap();
+        (String::from_utf8(out).unwrap(), failures)
+    }
+
+    fn mixed() -> Vec<Outcome> {
+        vec![
+            Outcome::Solved(Answer::Int(42), Duration::from_micros(1500)),
+            Outcome::Failed("day07/input.in: bad \"input\"".to_string()),
+            Outcome::Solved(Answer::Unsolved, Duration::from_micros(3)),
+        ]
+    }
+
+    #[test]
+    fn json_rows() {
+        let (out, _) = report(Format::Json, &mixed());
+        assert_eq!(out, "[\n  \
+            {\"day\": 7, \"part\": 1, \"status\": \"ok\", \"answer\": \"42\", \"time_us\": 1500},\n  \
+            {\"day\": 7, \"part\": 2, \"status\": \"error\", \"answer\": null, \"time_us\": null, \"error\": \"day07/input.in: bad \\\"input\\\"\"},\n  \
+            {\"day\": 7, \"part\": 3, \"status\": \"unsolved\", \"answer\": null, \"time_us\": 3}\n]\n");
+        assert_eq!(report(Format::Json, &[]).0, "[]\n");
+    }
+
+    #[test]
+    fn csv_rows() {
+        let (out, _) = report(Format::Csv, &mixed());
+        assert_eq!(out, "day,part,status,answer,time_us\n\
+            7,1,ok,42,1500\n\
+            7,2,error,\"day07/input.in: bad \"\"input\"\"\",\n\
+            7,3,unsolved,,3\n");
+    }
+
+    #[test]
+    fn csv_quotes_multi_line_answers() {
+        let letters = Outcome::Solved(Answer::Text("#..#\n#, #".to_string()), Duration::from_micros(9));
+        let (out, _) = report(Format::Csv, &[letters]);
+        assert_eq!(out, "day,part,status,answer,time_us\n7,1,ok,\"#..#\n#, #\",9\n");
+    }
+
+    #[test]
+    fn counts_failures() {
+        assert_eq!(report(Format::Csv, &mixed()).1, 1);
+        assert_eq!(report(Format::Json, &[]).1, 0);
+        let failed = || Outcome::Failed("missing".to_string());
+        assert_eq!(report(Format::Text, &[failed(), failed()]).1, 2);
+    }
+}
@@ -0,0 +1,219 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Pos;
+
+/// A point or vector on the integer plane, with `y` increasing downwards.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    /// Rotates a quarter turn anticlockwise (as seen on screen) about the origin.
+    pub fn rotate_left(self) -> Point {
+        Point { x: self.y, y: -self.x }
+    }
+
+    /// Rotates a quarter turn clockwise (as seen on screen) about the origin.
+    pub fn rotate_right(self) -> Point {
+        Point { x: -self.y, y: self.x }
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item=Point> {
+        Direction::ALL.iter().map(move |dir| self.step(*dir))
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item=Point> {
+        crate::grid::neighbors8(self.into()).map(Point::from)
+    }
+
+    /// The direction of an orthogonally adjacent point.
+    pub fn direction_to(self, other: Point) -> Option<Direction> {
+        Direction::ALL.iter().copied().find(|dir| self.step(*dir) == other)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        Point { x: self.x * scale, y: self.y * scale }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((x, y): Pos) -> Point {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for Pos {
+    fn from(point: Point) -> Pos {
+        (point.x, point.y)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Around => self.reverse(),
+            Turn::Straight => self,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Around,
+    Straight,
+}
+
+/// How a puzzle numbers some geometry value, e.g. the repair droid's movement commands.
+/// Implemented by a marker type per puzzle, since the same value has different codes in each.
+pub trait Code<T> {
+    /// The code for `value`, or `None` if the puzzle has no code for it.
+    fn encode(value: T) -> Option<i64>;
+    fn decode(code: i64) -> Option<T>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Direction::ALL.iter().copied() {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.turn(Turn::Straight), dir);
+            assert_eq!(dir.turn(Turn::Around), dir.reverse());
+            assert_eq!(dir.turn_right().delta(), dir.delta().rotate_right());
+            assert_eq!(dir.turn_left().delta(), dir.delta().rotate_left());
+        }
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn(Turn::Right), Direction::Up);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Point::new(1, 0).rotate_right(), Point::new(0, 1));
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(-2, 3), Point::new(4, -1));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(b.manhattan(a), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(a.manhattan(a), 0);
+        assert!(a.neighbors4().all(|x| x.manhattan(a) == 1));
+        assert!(a.neighbors8().all(|x| x.chebyshev(a) == 1));
+        assert_eq!(a.neighbors8().count(), 8);
+        assert_eq!(a.direction_to(Point::new(-2, 2)), Some(Direction::Up));
+        assert_eq!(a.direction_to(Point::new(-1, 4)), None);
+    }
+
+    #[test]
+    fn operators() {
+        let mut p = Point::new(3, -4);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -3));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, -5));
+        assert_eq!(-p, Point::new(-3, 4));
+        assert_eq!(p * -2, Point::new(-6, 8));
+        p += Point::new(-3, 4);
+        assert_eq!(p, Point::ORIGIN);
+        p -= Point::new(1, 2);
+        assert_eq!(p, Point::new(-1, -2));
+        assert_eq!(p.step(Direction::Down), Point::new(-1, -1));
+        assert_eq!(Point::from((5, 6)), Point::new(5, 6));
+        assert_eq!(Pos::from(Point::new(5, 6)), (5, 6));
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+use crate::Result;
+
+/// An `(x, y)` position, with `y` increasing downwards.
+pub type Pos = (i64, i64);
+
+/// Up, right, down, left.
+pub const DIRS4: [Pos; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Clockwise from up, including diagonals.
+pub const DIRS8: [Pos; 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+pub fn neighbors4(pos: Pos) -> impl Iterator<Item=Pos> {
+    DIRS4.iter().map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+}
+
+pub fn neighbors8(pos: Pos) -> impl Iterator<Item=Pos> {
+    DIRS8.iter().map(move |(dx, dy)| (pos.0 + dx, pos.1 + dy))
+}
+
+/// An inclusive bounding box.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_x: i64,
+    pub min_y: i64,
+    pub max_x: i64,
+    pub max_y: i64,
+}
+
+impl Bounds {
+    pub fn new(pos: Pos) -> Bounds {
+        Bounds { min_x: pos.0, min_y: pos.1, max_x: pos.0, max_y: pos.1 }
+    }
+
+    pub fn include(&mut self, pos: Pos) {
+        self.min_x = self.min_x.min(pos.0);
+        self.min_y = self.min_y.min(pos.1);
+        self.max_x = self.max_x.max(pos.0);
+        self.max_y = self.max_y.max(pos.1);
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 >= self.min_x && pos.0 <= self.max_x && pos.1 >= self.min_y && pos.1 <= self.max_y
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max_x - self.min_x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max_y - self.min_y + 1
+    }
+
+    /// Every position inside, row by row.
+    pub fn positions(self) -> impl Iterator<Item=Pos> {
+        (self.min_y..=self.max_y).flat_map(move |y| (self.min_x..=self.max_x).map(move |x| (x, y)))
+    }
+
+    /// Renders one character per position, rows separated by newlines.
+    pub fn render(&self, mut cell: impl FnMut(Pos) -> char) -> String {
+        let mut rows = Vec::new();
+        for y in self.min_y..=self.max_y {
+            rows.push((self.min_x..=self.max_x).map(|x| cell((x, y))).collect::<String>());
+        }
+        rows.join("\n")
+    }
+}
+
+/// A rectangular grid with a cell at every position from `(0, 0)` to `(width - 1, height - 1)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid from row-major cells. Panics if there aren't exactly `width * height`.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "wrong number of cells for a {}x{} grid", width, height);
+        Grid { width, height, cells }
+    }
+
+    /// Parses one cell per character, one row per line. Every row must be the same length, and
+    /// `cell` returns `None` for characters that aren't allowed.
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (y, line) in text.lines().enumerate() {
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return Err(format!("row {} has {} cells, expected {}", y + 1, len, width.unwrap()).into());
+            }
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or_else(|| format!("invalid cell `{}` at {}, {}", c, x, y))?);
+            }
+            height += 1;
+        }
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds { min_x: 0, min_y: 0, max_x: self.width as i64 - 1, max_y: self.height as i64 - 1 }
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 >= 0 && pos.1 >= 0 && (pos.0 as usize) < self.width && (pos.1 as usize) < self.height
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.1 as usize * self.width + pos.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=(Pos, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, cell)| (((i % width) as i64, (i / width) as i64), cell))
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item=Pos> + '_ {
+        neighbors4(pos).filter(move |x| self.contains(*x))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item=Pos> + '_ {
+        neighbors8(pos).filter(move |x| self.contains(*x))
+    }
+
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        self.bounds().render(|pos| cell(&self[pos]))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+/// A grid that only stores the cells that have been set, over any range of positions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new(), bounds: None }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Parses one line per row starting at `(0, 0)`, keeping the cells that `cell` returns.
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> Option<T>) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (y, line) in text.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(value) = cell(c) {
+                    grid.insert((x as i64, y as i64), value);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(pos),
+            None => self.bounds = Some(Bounds::new(pos)),
+        }
+        self.cells.insert(pos, value)
+    }
+
+    /// Removes a cell. The bounds still cover it.
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest box around every position ever set, or `None` if nothing has been.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(Pos, &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item=Pos> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item=&T> {
+        self.cells.values()
+    }
+
+    /// The orthogonal neighbours of `pos` that have been set.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item=Pos> + '_ {
+        neighbors4(pos).filter(move |x| self.contains(*x))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that have been set.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item=Pos> + '_ {
+        neighbors8(pos).filter(move |x| self.contains(*x))
+    }
+
+    /// Renders the bounding box; `cell` gets `None` for positions that were never set.
+    pub fn render(&self, mut cell: impl FnMut(Option<&T>) -> char) -> String {
+        match self.bounds {
+            Some(bounds) => bounds.render(|pos| cell(self.get(pos))),
+            None => String::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item=(Pos, T)>>(iter: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (pos, value) in iter {
+            grid.insert(pos, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wall(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn sorted(positions: impl Iterator<Item=Pos>) -> Vec<Pos> {
+        let mut positions: Vec<Pos> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = Grid::parse("#..\n.#.\n", wall).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(1, 1)] && !grid[(1, 0)]);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.render(|x| if *x { '#' } else { '.' }), "#..\n.#.");
+
+        assert_eq!(Grid::parse("#..\n.#\n", wall).unwrap_err().to_string(), "row 2 has 2 cells, expected 3");
+        assert_eq!(Grid::parse("#..\n.x.", wall).unwrap_err().to_string(), "invalid cell `x` at 1, 1");
+
+        let empty = Grid::parse("", wall).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.render(|_| '#'), "");
+    }
+
+    #[test]
+    fn neighbours_at_edges() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(sorted(grid.neighbors4((0, 0))), vec![(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbors8((0, 0))), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.neighbors4((1, 1))), vec![(0, 1), (1, 0), (2, 1)]);
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbors8((5, 5)).count(), 0);
+        assert_eq!(neighbors4((0, 0)).collect::<Vec<_>>(), DIRS4.to_vec());
+        assert_eq!(neighbors8((10, -3)).count(), 8);
+
+        let sparse = SparseGrid::parse("#.\n##", |c| if c == '#' { Some(()) } else { None });
+        assert_eq!(sorted(sparse.neighbors4((0, 0))), vec![(0, 1)]);
+        assert_eq!(sorted(sparse.neighbors8((0, 0))), vec![(0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn bounds() {
+        let mut bounds = Bounds::new((2, 3));
+        assert_eq!((bounds.width(), bounds.height()), (1, 1));
+        bounds.include((-1, 5));
+        bounds.include((0, 4));
+        assert_eq!(bounds, Bounds { min_x: -1, min_y: 3, max_x: 2, max_y: 5 });
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+        assert!(bounds.contains((-1, 3)) && bounds.contains((2, 5)));
+        assert!(!bounds.contains((3, 5)) && !bounds.contains((0, 2)));
+        assert_eq!(bounds.positions().count(), 12);
+        assert_eq!(bounds.positions().next(), Some((-1, 3)));
+    }
+
+    #[test]
+    fn renders_sparse_grids() {
+        let mut grid: SparseGrid<char> = [((-1, -1), 'a'), ((1, 0), 'b')].iter().cloned().collect();
+        assert_eq!(grid.render(|x| *x.unwrap_or(&' ')), "a  \n  b");
+        grid.remove((1, 0));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.render(|x| *x.unwrap_or(&'.')), "a..\n...");
+        assert_eq!(SparseGrid::<char>::new().render(|_| '#'), "");
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+}
@@ -0,0 +1,170 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::grid::{Grid, SparseGrid};
+use crate::Result;
+
+pub type Rgb = [u8; 3];
+
+/// Colours for the cell values of a `Picture`, by index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Palette(pub Vec<Rgb>);
+
+/// Drawn for cell values the palette has no colour for.
+const MISSING: Rgb = [255, 0, 255];
+
+impl Palette {
+    /// 0 is black and 1 is white.
+    pub fn mono() -> Palette {
+        Palette(vec![[0, 0, 0], [255, 255, 255]])
+    }
+
+    /// Parses comma-separated `rrggbb` colours, each optionally prefixed with `#`.
+    pub fn parse(text: &str) -> Result<Palette> {
+        let mut colors = Vec::new();
+        for color in text.split(',') {
+            let hex = color.trim().trim_start_matches('#');
+            if hex.len() != 6 || !hex.is_ascii() {
+                return Err(format!("invalid colour `{}`, expected rrggbb", color).into());
+            }
+            let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+            colors.push([channel(0)?, channel(2)?, channel(4)?]);
+        }
+        Ok(Palette(colors))
+    }
+
+    pub fn get(&self, index: u8) -> Rgb {
+        *self.0.get(index as usize).unwrap_or(&MISSING)
+    }
+}
+
+/// A puzzle's visual output: a palette index per cell and the colours to draw them in.
+pub struct Picture {
+    pub cells: Grid<u8>,
+    pub palette: Palette,
+}
+
+impl Picture {
+    pub fn new(cells: Grid<u8>, palette: Palette) -> Picture {
+        Picture { cells, palette }
+    }
+
+    /// Covers the bounding box of a sparse grid; `index` gets `None` for cells that were never set.
+    pub fn from_sparse<T>(grid: &SparseGrid<T>, mut index: impl FnMut(Option<&T>) -> u8, palette: Palette) -> Picture {
+        let cells = match grid.bounds() {
+            Some(bounds) => {
+                let cells = bounds.positions().map(|pos| index(grid.get(pos))).collect();
+                Grid::from_cells(bounds.width() as usize, bounds.height() as usize, cells)
+            }
+            None => Grid::from_cells(0, 0, Vec::new()),
+        };
+        Picture { cells, palette }
+    }
+
+    /// Each cell becomes a `scale` by `scale` block of pixels.
+    pub fn bitmap(&self, scale: usize) -> Bitmap {
+        let width = self.cells.width() * scale;
+        let height = self.cells.height() * scale;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let cell = self.cells[((x / scale) as i64, (y / scale) as i64)];
+                pixels.push(self.palette.get(cell));
+            }
+        }
+        Bitmap { width, height, pixels }
+    }
+}
+
+pub struct Bitmap {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Bitmap {
+    /// Binary PPM (P6).
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let data: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        out.write_all(&data)
+    }
+
+    /// Binary PBM (P4). Pixels darker than mid-grey are black.
+    pub fn write_pbm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P4\n{} {}\n", self.width, self.height)?;
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let mut packed = vec![0_u8; self.width.div_ceil(8)];
+            for (x, [r, g, b]) in row.iter().enumerate() {
+                let luma = (299 * *r as u32 + 587 * *g as u32 + 114 * *b as u32) / 1000;
+                if luma < 128 {
+                    packed[x / 8] |= 0x80 >> (x % 8);
+                }
+            }
+            out.write_all(&packed)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        let data: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        writer.write_image_data(&data)?;
+        Ok(())
+    }
+
+    /// Writes in the format named by the file extension: `ppm`, `pbm`, or `png` if built with
+    /// the `png` feature.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|x| x.to_str()).unwrap_or("");
+        let format_error = || format!("{}: unsupported image format `{}`", path.display(), extension);
+        match extension {
+            "ppm" => self.write_ppm(BufWriter::new(File::create(path)?))?,
+            "pbm" => self.write_pbm(BufWriter::new(File::create(path)?))?,
+            #[cfg(feature = "png")]
+            "png" => self.write_png(BufWriter::new(File::create(path)?))?,
+            #[cfg(not(feature = "png"))]
+            "png" => return Err(format!("{}: built without PNG support, enable the `png` feature", path.display()).into()),
+            _ => return Err(format_error().into()),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_pbm_rows() {
+        // Ten pixels a row, so each row takes two bytes with the last six bits unused.
+        let cells = Grid::parse("#.#......#\n.########.", |c| Some((c == '.') as u8)).unwrap();
+        let bitmap = Picture::new(cells, Palette::mono()).bitmap(1);
+        let mut out = Vec::new();
+        bitmap.write_pbm(&mut out).unwrap();
+        assert_eq!(out, [&b"P4\n10 2\n"[..], &[0b1010_0000, 0b0100_0000, 0b0111_1111, 0b1000_0000]].concat());
+
+        // Colours are split on brightness rather than on the index.
+        let bitmap = Bitmap { width: 3, height: 1, pixels: vec![[255, 0, 0], [0, 255, 0], [127, 128, 128]] };
+        out.clear();
+        bitmap.write_pbm(&mut out).unwrap();
+        assert_eq!(out, [&b"P4\n3 1\n"[..], &[0b1010_0000]].concat());
+    }
+
+    #[test]
+    fn scales_pixels() {
+        let cells = Grid::from_cells(2, 1, vec![0, 7]);
+        let bitmap = Picture::new(cells, Palette::parse("#102030,405060").unwrap()).bitmap(2);
+        assert_eq!((bitmap.width, bitmap.height), (4, 2));
+        let mut out = Vec::new();
+        bitmap.write_ppm(&mut out).unwrap();
+        let row = [0x10, 0x20, 0x30, 0x10, 0x20, 0x30, 255, 0, 255, 255, 0, 255];
+        assert_eq!(out, [&b"P6\n4 2\n255\n"[..], &row, &row].concat());
+    }
+}
@@ -0,0 +1,240 @@
+pub mod escape;
+pub mod geom;
+pub mod grid;
+pub mod image;
+pub mod ocr;
+pub mod search;
+
+use std::any::Any;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use crate::image::Picture;
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Prints `message` to stderr and exits with status 2, for bad command lines and unusable files.
+pub fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(2);
+}
+
+/// The input file for `day`, relative to the workspace root.
+pub fn default_input(day: u32) -> String {
+    format!("day{:02}/input.in", day)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(val) => write!(f, "{}", val),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "(unsolved)"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(val: i64) -> Answer {
+        Answer::Int(val)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(val: i32) -> Answer {
+        Answer::Int(val.into())
+    }
+}
+
+/// Values too large for `i64` become text.
+impl From<u64> for Answer {
+    fn from(val: u64) -> Answer {
+        match i64::try_from(val) {
+            Ok(val) => Answer::Int(val),
+            Err(_) => Answer::Text(val.to_string()),
+        }
+    }
+}
+
+/// Values too large for `i64` become text.
+impl From<usize> for Answer {
+    fn from(val: usize) -> Answer {
+        match i64::try_from(val) {
+            Ok(val) => Answer::Int(val),
+            Err(_) => Answer::Text(val.to_string()),
+        }
+    }
+}
+
+/// Values outside the range of `i64` become text.
+impl From<i128> for Answer {
+    fn from(val: i128) -> Answer {
+        match i64::try_from(val) {
+            Ok(val) => Answer::Int(val),
+            Err(_) => Answer::Text(val.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// One day's puzzle. The input file is parsed once and both parts solve from the result.
+pub trait Solution {
+    const DAY: u32;
+    const NAME: &'static str;
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// What a part looks like, for puzzles whose answer is drawn rather than computed.
+    fn picture(_input: &Self::Input, _part: u32) -> Option<Picture> {
+        None
+    }
+}
+
+fn parse_any<S: Solution>(input: &str) -> Result<Box<dyn Any>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1_any<S: Solution>(input: &dyn Any) -> Answer {
+    S::part1(input.downcast_ref().unwrap())
+}
+
+fn part2_any<S: Solution>(input: &dyn Any) -> Answer {
+    S::part2(input.downcast_ref().unwrap())
+}
+
+fn picture_any<S: Solution>(input: &dyn Any, part: u32) -> Option<Picture> {
+    S::picture(input.downcast_ref().unwrap(), part)
+}
+
+/// A `Solution` with its types erased, so the runner can keep every day in one list.
+pub struct Day {
+    pub day: u32,
+    pub name: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> Answer,
+    part2: fn(&dyn Any) -> Answer,
+    picture: fn(&dyn Any, u32) -> Option<Picture>,
+}
+
+impl Day {
+    pub fn new<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            name: S::NAME,
+            parse: parse_any::<S>,
+            part1: part1_any::<S>,
+            part2: part2_any::<S>,
+            picture: picture_any::<S>,
+        }
+    }
+
+    /// Parses the input file. The result can only be passed back to `solve` on this same day.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        (self.parse)(input)
+    }
+
+    /// Fails if `part` isn't 1 or 2.
+    pub fn solve(&self, part: u32, input: &dyn Any) -> Result<Answer> {
+        match part {
+            1 => Ok((self.part1)(input)),
+            2 => Ok((self.part2)(input)),
+            _ => Err(format!("day {} has no part {}", self.day, part).into()),
+        }
+    }
+
+    pub fn picture(&self, part: u32, input: &dyn Any) -> Option<Picture> {
+        (self.picture)(input, part)
+    }
+}
+
+/// Checks a solution against the examples given in its puzzle text:
+///
+/// ```ignore
+/// common::examples!(Day01 {
+///     mass_12: part1("12") == 2,
+///     mass_1969: part2("1969") == 966,
+/// });
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty { $($name:ident: $part:ident($input:expr) == $answer:expr),* $(,)? }) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+            use $crate::Solution;
+
+            $(
+                #[test]
+                fn $name() {
+                    let input = <$solution>::parse($input).unwrap();
+                    assert_eq!(<$solution>::$part(&input), $crate::Answer::from($answer));
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u32 = 1;
+        const NAME: &'static str = "Echo";
+        type Input = String;
+
+        fn parse(input: &str) -> Result<String> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &String) -> Answer {
+            input.len().into()
+        }
+
+        fn part2(input: &String) -> Answer {
+            input.as_str().into()
+        }
+    }
+
+    #[test]
+    fn large_answers_become_text() {
+        assert_eq!(Answer::from(u64::MAX), Answer::Text("18446744073709551615".to_string()));
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Int(i64::MAX));
+        assert_eq!(Answer::from(usize::MAX), Answer::Text(usize::MAX.to_string()));
+        assert_eq!(Answer::from(-(1i128 << 64)), Answer::Text("-18446744073709551616".to_string()));
+    }
+
+    #[test]
+    fn only_two_parts() {
+        let day = Day::new::<Echo>();
+        let input = day.parse("abc").unwrap();
+        assert_eq!(day.solve(1, &*input).unwrap(), Answer::Int(3));
+        assert_eq!(day.solve(2, &*input).unwrap(), Answer::Text("abc".to_string()));
+        assert_eq!(day.solve(3, &*input).unwrap_err().to_string(), "day 1 has no part 3");
+        assert_eq!(day.solve(0, &*input).unwrap_err().to_string(), "day 1 has no part 0");
+    }
+}
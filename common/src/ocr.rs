@@ -0,0 +1,178 @@
+use crate::grid::{Bounds, Grid, Pos};
+use crate::Result;
+
+/// The letters of the 4x6 font used by most puzzles that draw their answer, in the order
+/// they appear in `SMALL_FONT`.
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+const SMALL_FONT: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+    "#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+    "####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+    "#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+];
+
+/// The letters of the larger 6x10 font.
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+const LARGE_FONT: [&str; 10] = [
+    "..##...#####...####..######.######..####..#....#....###.#....#.#......#....#.#####..#####..#....#.######",
+    ".#..#..#....#.#....#.#......#......#....#.#....#.....#..#...#..#......##...#.#....#.#....#.#....#......#",
+    "#....#.#....#.#......#......#......#......#....#.....#..#..#...#......##...#.#....#.#....#..#..#.......#",
+    "#....#.#....#.#......#......#......#......#....#.....#..#.#....#......#.#..#.#....#.#....#..#..#......#.",
+    "#....#.#####..#......#####..#####..#......######.....#..##.....#......#.#..#.#####..#####....##......#..",
+    "######.#....#.#......#......#......#..###.#....#.....#..##.....#......#..#.#.#......#..#.....##.....#...",
+    "#....#.#....#.#......#......#......#....#.#....#.....#..#.#....#......#..#.#.#......#...#...#..#...#....",
+    "#....#.#....#.#......#......#......#....#.#....#.#...#..#..#...#......#...##.#......#...#...#..#..#.....",
+    "#....#.#....#.#....#.#......#......#...##.#....#.#...#..#...#..#......#...##.#......#....#.#....#.#.....",
+    "#....#.#####...####..######.#.......###.#.#....#..###...#....#.######.#....#.#......#....#.#....#.######",
+];
+
+/// Splits columns (each a bitmask of its lit rows) into glyphs at the blank columns, returning
+/// each glyph with the index of its first column.
+fn split(columns: &[u16]) -> Vec<(usize, &[u16])> {
+    let mut glyphs = Vec::new();
+    let mut start = None;
+    for (x, column) in columns.iter().enumerate() {
+        match (start, *column != 0) {
+            (None, true) => start = Some(x),
+            (Some(first), false) => {
+                glyphs.push((first, &columns[first..x]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(first) = start {
+        glyphs.push((first, &columns[first..]));
+    }
+    glyphs
+}
+
+fn font_columns(rows: &[&str]) -> Vec<u16> {
+    (0..rows[0].len()).map(|x| {
+        rows.iter().enumerate()
+            .filter(|(_, row)| row.as_bytes()[x] == b'#')
+            .fold(0, |mask, (y, _)| mask | 1 << y)
+    }).collect()
+}
+
+/// Reads block letters from the lit cells inside `bounds`. Blank rows around the text are
+/// ignored; its height picks the font. Letters must be separated by at least one blank column.
+pub fn recognize(bounds: Bounds, lit: impl Fn(Pos) -> bool) -> Result<String> {
+    let rows: Vec<i64> = (bounds.min_y..=bounds.max_y)
+        .filter(|y| (bounds.min_x..=bounds.max_x).any(|x| lit((x, *y))))
+        .collect();
+    let (top, bottom) = match (rows.first(), rows.last()) {
+        (Some(top), Some(bottom)) => (*top, *bottom),
+        _ => return Err("no lit cells to read".into()),
+    };
+
+    let (font, letters): (&[&str], &str) = match bottom - top + 1 {
+        6 => (&SMALL_FONT, SMALL_LETTERS),
+        10 => (&LARGE_FONT, LARGE_LETTERS),
+        height => return Err(format!("text is {} rows high, expected 6 or 10", height).into()),
+    };
+    let reference = font_columns(font);
+    let glyphs: Vec<(&[u16], char)> = split(&reference).into_iter().map(|(_, glyph)| glyph).zip(letters.chars()).collect();
+
+    let columns: Vec<u16> = (bounds.min_x..=bounds.max_x).map(|x| {
+        (top..=bottom).filter(|y| lit((x, *y))).fold(0, |mask, y| mask | 1 << (y - top))
+    }).collect();
+
+    let mut text = String::new();
+    for (x, glyph) in split(&columns) {
+        match glyphs.iter().find(|(known, _)| *known == glyph) {
+            Some((_, letter)) => text.push(*letter),
+            None => return Err(format!("unrecognised letter at column {}", bounds.min_x + x as i64).into()),
+        }
+    }
+    Ok(text)
+}
+
+pub fn recognize_grid(grid: &Grid<bool>) -> Result<String> {
+    recognize(grid.bounds(), |pos| grid[pos])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::SparseGrid;
+
+    fn read(rows: &[&str]) -> Result<String> {
+        let grid = Grid::parse(&rows.join("\n"), |c| Some(c == '#')).unwrap();
+        recognize_grid(&grid)
+    }
+
+    #[test]
+    fn reads_both_fonts() {
+        assert_eq!(read(&SMALL_FONT).unwrap(), SMALL_LETTERS);
+        assert_eq!(read(&LARGE_FONT).unwrap(), LARGE_LETTERS);
+    }
+
+    #[test]
+    fn ignores_padding() {
+        let rows = [
+            "..........",
+            ".#..#.###.",
+            ".#..#..#..",
+            ".####..#..",
+            ".#..#..#..",
+            ".#..#..#..",
+            ".#..#.###.",
+            "..........",
+        ];
+        assert_eq!(read(&rows).unwrap(), "HI");
+    }
+
+    #[test]
+    fn reads_space_image() {
+        // A 25x6 image from the space image format puzzle, `1` being white.
+        let image = [
+            "1111010010100100110000110",
+            "0001010010101001001000010",
+            "0010010010110001000000010",
+            "0100010010101001000000010",
+            "1000010010101001001010010",
+            "1111001100100100110001100",
+        ];
+        let grid = Grid::parse(&image.join("\n"), |c| Some(c == '1')).unwrap();
+        assert_eq!(recognize_grid(&grid).unwrap(), "ZUKCJ");
+    }
+
+    #[test]
+    fn reads_painted_hull() {
+        // The hull painting robot starts at the second column and leaves some black panels
+        // around the text.
+        let hull = [
+            " ###  #    #  # #    ####   ## #    ####   ",
+            " #  # #    #  # #       #    # #    #      ",
+            " ###  #    #  # #      #     # #    ###    ",
+            " #  # #    #  # #     #      # #    #      ",
+            " #  # #    #  # #    #    #  # #    #      ",
+            " ###  ####  ##  #### ####  ##  #### ####   ",
+        ];
+        let mut panels = SparseGrid::parse(&hull.join("\n"), |c| Some((c == '#') as i64));
+        panels.insert((-2, -1), 0);
+        panels.insert((50, 7), 0);
+        let bounds = panels.bounds().unwrap();
+        assert_eq!(recognize(bounds, |pos| panels.get(pos) == Some(&1)).unwrap(), "BLULZJLE");
+    }
+
+    #[test]
+    fn rejects_unknown_letters() {
+        // The middle letter is a D, which the font doesn't have.
+        let rows = [
+            "###..###..###.",
+            "#..#.#..#.#..#",
+            "#..#.#..#.#..#",
+            "###..#..#.###.",
+            "#....#..#.#.#.",
+            "#....###..#..#",
+        ];
+        assert_eq!(read(&rows).unwrap_err().to_string(), "unrecognised letter at column 5");
+
+        let rows = ["#.#", ".#.", "#.#", ".#.", "#.#", ".#."];
+        assert!(read(&rows).is_err());
+    }
+}
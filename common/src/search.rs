@@ -0,0 +1,188 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The shortest distances from a start node to everything a search reached, and the
+/// predecessor of each node on one shortest path.
+pub struct Paths<N> {
+    start: N,
+    dist: HashMap<N, u64>,
+    prev: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    fn new(start: N) -> Paths<N> {
+        let mut dist = HashMap::new();
+        dist.insert(start.clone(), 0);
+        Paths { start, dist, prev: HashMap::new() }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, u64> {
+        &self.dist
+    }
+
+    /// The nodes from the start to `node`, inclusive of both.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        let mut curr = node;
+        while let Some(prev) = self.prev.get(curr) {
+            path.push(prev.clone());
+            curr = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// A node as far from the start as any other, with its distance.
+    pub fn farthest(&self) -> (&N, u64) {
+        let (node, dist) = self.dist.iter().max_by_key(|(_, dist)| **dist).unwrap();
+        (node, *dist)
+    }
+}
+
+/// Breadth-first search over unweighted edges, visiting everything reachable from `start`.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Paths<N>
+    where N: Clone + Eq + Hash, I: IntoIterator<Item=N> {
+    let mut paths = Paths::new(start.clone());
+    let mut fringe = VecDeque::new();
+    fringe.push_back(start);
+
+    while let Some(curr) = fringe.pop_front() {
+        let dist = paths.dist[&curr];
+        for next in neighbors(&curr) {
+            if paths.dist.contains_key(&next) {
+                continue;
+            }
+            paths.dist.insert(next.clone(), dist + 1);
+            paths.prev.insert(next.clone(), curr.clone());
+            fringe.push_back(next);
+        }
+    }
+    paths
+}
+
+/// How many steps it takes something spreading one edge per step from `start` to fill
+/// everything reachable.
+pub fn time_to_fill<N, I>(start: N, neighbors: impl FnMut(&N) -> I) -> u64
+    where N: Clone + Eq + Hash, I: IntoIterator<Item=N> {
+    bfs(start, neighbors).farthest().1
+}
+
+/// Dijkstra's algorithm over edges with non-negative costs, visiting everything reachable from `start`.
+pub fn dijkstra<N, I>(start: N, neighbors: impl FnMut(&N) -> I) -> Paths<N>
+    where N: Clone + Ord + Hash, I: IntoIterator<Item=(N, u64)> {
+    let (paths, _) = best_first(start, neighbors, |_| 0, |_| false);
+    paths
+}
+
+/// A* search from `start` to the nearest node satisfying `is_goal`. `heuristic` must never
+/// overestimate the remaining cost. Returns the cost and the path, start and goal included.
+pub fn astar<N, I>(start: N, neighbors: impl FnMut(&N) -> I, heuristic: impl FnMut(&N) -> u64,
+                   is_goal: impl FnMut(&N) -> bool) -> Option<(u64, Vec<N>)>
+    where N: Clone + Ord + Hash, I: IntoIterator<Item=(N, u64)> {
+    let (paths, goal) = best_first(start, neighbors, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.dist[&goal], paths.path_to(&goal).unwrap()))
+}
+
+fn best_first<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I, mut heuristic: impl FnMut(&N) -> u64,
+                    mut is_goal: impl FnMut(&N) -> bool) -> (Paths<N>, Option<N>)
+    where N: Clone + Ord + Hash, I: IntoIterator<Item=(N, u64)> {
+    let mut paths = Paths::new(start.clone());
+    let mut fringe = BinaryHeap::new();
+    fringe.push(Reverse((heuristic(&start), 0, start)));
+
+    while let Some(Reverse((_, dist, curr))) = fringe.pop() {
+        if dist > paths.dist[&curr] {
+            // Already reached more cheaply.
+            continue;
+        }
+        if is_goal(&curr) {
+            return (paths, Some(curr));
+        }
+        for (next, cost) in neighbors(&curr) {
+            let next_dist = dist + cost;
+            if paths.dist.get(&next).is_some_and(|old| *old <= next_dist) {
+                continue;
+            }
+            paths.dist.insert(next.clone(), next_dist);
+            paths.prev.insert(next.clone(), curr.clone());
+            fringe.push(Reverse((next_dist + heuristic(&next), next_dist, next)));
+        }
+    }
+    (paths, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Point;
+    use crate::grid::Grid;
+
+    /// `a` to `d` with two routes that cost less than their direct edges, and an unreachable `e`.
+    fn graph(node: &char) -> Vec<(char, u64)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 4)],
+            'b' => vec![('c', 2), ('d', 6)],
+            'c' => vec![('d', 3)],
+            'e' => vec![('a', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_distances() {
+        let paths = dijkstra('a', graph);
+        assert_eq!(*paths.start(), 'a');
+        let mut distances: Vec<(char, u64)> = paths.distances().iter().map(|(n, d)| (*n, *d)).collect();
+        distances.sort();
+        assert_eq!(distances, vec![('a', 0), ('b', 1), ('c', 3), ('d', 6)]);
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(paths.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(paths.path_to(&'e'), None);
+        assert_eq!(paths.farthest(), (&'d', 6));
+    }
+
+    #[test]
+    fn bfs_ignores_weights() {
+        let paths = bfs('a', |node| graph(node).into_iter().map(|(next, _)| next));
+        assert_eq!(paths.distance(&'d'), Some(2));
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'd']));
+        assert_eq!(paths.distance(&'e'), None);
+        assert_eq!(time_to_fill('a', |node| graph(node).into_iter().map(|(next, _)| next)), 2);
+    }
+
+    #[test]
+    fn astar_on_a_weighted_grid() {
+        // Each cell costs its digit to enter; the cheap way round beats the direct route.
+        let grid = Grid::parse("1911\n1#91\n1111", |c| match c {
+            '#' => Some(None),
+            _ => c.to_digit(10).map(|x| Some(x as u64)),
+        }).unwrap();
+        let neighbors = |pos: &Point| -> Vec<(Point, u64)> {
+            pos.neighbors4().filter_map(|next| grid.get(next.into()).copied().flatten().map(|cost| (next, cost))).collect()
+        };
+        let goal = Point::new(3, 0);
+
+        // Manhattan distance is admissible since every cell costs at least 1.
+        let (cost, path) = astar(Point::ORIGIN, neighbors, |pos| pos.manhattan(goal) as u64, |pos| *pos == goal).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.len(), 8);
+        assert_eq!((path[0], path[7]), (Point::ORIGIN, goal));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+        assert_eq!(dijkstra(Point::ORIGIN, neighbors).distance(&goal), Some(cost));
+
+        assert_eq!(astar(Point::ORIGIN, neighbors, |_| 0, |pos| *pos == Point::new(1, 1)), None);
+    }
+}
@@ -0,0 +1,50 @@
+use std::env;
+use std::fs::{self, File};
+use std::io::BufReader;
+
+use common::fail;
+use day01::fuel::{parse_manifest, stream_totals, Formula, Plan};
+
+const USAGE: &str = "usage: fuel <manifest> [--divisor n] [--subtrahend n] [--rounding floor|ceil|nearest] [--format csv|json|summary]";
+
+fn main() {
+    let mut manifest = None;
+    let default = Formula::default();
+    let (mut divisor, mut subtrahend, mut rounding) = (default.divisor(), default.subtrahend(), default.rounding());
+    let mut format = "csv".to_string();
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().unwrap_or_else(|| fail(&format!("{} needs a value", flag))).clone();
+        match arg.as_str() {
+            "--divisor" => divisor = value("--divisor").parse().ok().filter(|x| *x > 0)
+                .unwrap_or_else(|| fail("--divisor must be a positive number")),
+            "--subtrahend" => subtrahend = value("--subtrahend").parse()
+                .unwrap_or_else(|_| fail("--subtrahend must be a number")),
+            "--rounding" => rounding = value("--rounding").parse().unwrap_or_else(|e: String| fail(&e)),
+            "--format" => format = match value("--format").as_str() {
+                x @ "csv" | x @ "json" | x @ "summary" => x.to_string(),
+                _ => fail("--format must be csv, json or summary"),
+            },
+            _ if manifest.is_none() => manifest = Some(arg.clone()),
+            _ => fail(USAGE),
+        }
+    }
+
+    let path = manifest.unwrap_or_else(|| fail(USAGE));
+    let formula = Formula::new(divisor, subtrahend, rounding).unwrap_or_else(|e| fail(&e));
+    if format == "summary" {
+        // Only the totals are needed, so don't hold the whole manifest in memory.
+        let file = File::open(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+        let totals = stream_totals(BufReader::new(file), &formula).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+        println!("modules: {}\nfuel: {}\ntotal: {}", totals.modules, totals.fuel, totals.total);
+        return;
+    }
+
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    let modules = parse_manifest(&text).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+
+    let plan = Plan::new(formula, &modules).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    print!("{}", if format == "json" { plan.to_json() } else { plan.to_csv() });
+}
@@ -0,0 +1,339 @@
+use std::io::BufRead;
+use std::str::FromStr;
+
+use common::escape::{csv_field, json_string};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Floor,
+    Ceil,
+    Nearest,
+}
+
+impl FromStr for Rounding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Rounding, String> {
+        match s {
+            "floor" => Ok(Rounding::Floor),
+            "ceil" => Ok(Rounding::Ceil),
+            "nearest" => Ok(Rounding::Nearest),
+            _ => Err(format!("invalid rounding `{}`, expected floor, ceil or nearest", s)),
+        }
+    }
+}
+
+/// Fuel for a mass: `round(mass / divisor) - subtrahend`, never below zero. Everything is
+/// computed in `i128`, so any mass that fits in an `i64` or `u64` is handled exactly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Formula {
+    divisor: i64,
+    subtrahend: i64,
+    rounding: Rounding,
+}
+
+impl Default for Formula {
+    /// The formula from the puzzle: divide by three, round down, subtract two.
+    fn default() -> Formula {
+        Formula { divisor: 3, subtrahend: 2, rounding: Rounding::Floor }
+    }
+}
+
+impl Formula {
+    /// Fails unless `divisor` is positive.
+    pub fn new(divisor: i64, subtrahend: i64, rounding: Rounding) -> Result<Formula, String> {
+        if divisor <= 0 {
+            return Err(format!("divisor must be positive, got {}", divisor));
+        }
+        Ok(Formula { divisor, subtrahend, rounding })
+    }
+
+    pub fn divisor(&self) -> i64 {
+        self.divisor
+    }
+
+    pub fn subtrahend(&self) -> i64 {
+        self.subtrahend
+    }
+
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    pub fn fuel(&self, mass: i128) -> i128 {
+        let d = self.divisor as i128;
+        let (q, r) = (mass.div_euclid(d), mass.rem_euclid(d));
+        let quotient = match self.rounding {
+            Rounding::Floor => q,
+            Rounding::Ceil => q + (r != 0) as i128,
+            // `r < d`, so doubling it can't overflow.
+            Rounding::Nearest => q + (2 * r >= d) as i128,
+        };
+        quotient.saturating_sub(self.subtrahend as i128).max(0)
+    }
+
+    /// Fuel for a mass, then fuel for that fuel, and so on while it's still positive.
+    pub fn increments(&self, mass: i128) -> Increments {
+        Increments { formula: *self, mass, first: true }
+    }
+
+    /// The sum of `increments` and how many there were, or `None` if the sum overflows.
+    pub fn fuel_for_fuel(&self, mass: i128) -> Option<(i128, u32)> {
+        let mut total_fuel: i128 = 0;
+        let mut iterations = 0;
+        for fuel in self.increments(mass) {
+            total_fuel = total_fuel.checked_add(fuel)?;
+            iterations += 1;
+        }
+        Some((total_fuel, iterations))
+    }
+}
+
+/// The iterator returned by `Formula::increments`. With the puzzle's formula each increment is
+/// about a third of the last, so there are at most a few dozen. A formula that doesn't shrink
+/// the fuel would never finish, so the sequence also stops at the first increment that isn't
+/// smaller than the one before.
+pub struct Increments {
+    formula: Formula,
+    mass: i128,
+    first: bool,
+}
+
+impl Iterator for Increments {
+    type Item = i128;
+
+    fn next(&mut self) -> Option<i128> {
+        let fuel = self.formula.fuel(self.mass);
+        if fuel <= 0 || (!self.first && fuel >= self.mass) {
+            return None;
+        }
+        self.first = false;
+        self.mass = fuel;
+        Some(fuel)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub mass: i128,
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(i) => &line[..i],
+        None => line,
+    }.trim()
+}
+
+/// Parses one non-blank manifest line; `number` is used for the default name and in errors.
+fn parse_module(line: &str, number: usize) -> common::Result<Module> {
+    let (name, mass) = match line.rsplit_once(char::is_whitespace) {
+        Some((name, mass)) => (name.trim().to_string(), mass),
+        None => (format!("module-{}", number), line),
+    };
+    let mass = mass.parse::<i128>().map_err(|e| format!("line {}: invalid mass `{}`: {}", number, mass, e))?;
+    Ok(Module { name, mass })
+}
+
+/// Parses a manifest with one module per line, either `<mass>` or `<name> <mass>`. Unnamed
+/// modules are called `module-<line>`. Blank lines and `#` comments are skipped.
+pub fn parse_manifest(text: &str) -> common::Result<Vec<Module>> {
+    let mut modules = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = strip_comment(line);
+        if !line.is_empty() {
+            modules.push(parse_module(line, index + 1)?);
+        }
+    }
+    Ok(modules)
+}
+
+/// Fuel summed over many modules.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub modules: u64,
+    /// Fuel for the modules' own mass.
+    pub fuel: i128,
+    /// Fuel including the fuel needed to carry the fuel.
+    pub total: i128,
+}
+
+impl Totals {
+    /// Adds a module and returns its `fuel_for_fuel`.
+    pub fn add(&mut self, formula: &Formula, mass: i128) -> common::Result<(i128, u32)> {
+        let modules = self.modules;
+        let overflow = || format!("fuel total overflowed after {} modules", modules);
+        let (total, iterations) = formula.fuel_for_fuel(mass).ok_or_else(overflow)?;
+        self.fuel = self.fuel.checked_add(formula.fuel(mass)).ok_or_else(overflow)?;
+        self.total = self.total.checked_add(total).ok_or_else(overflow)?;
+        self.modules += 1;
+        Ok((total, iterations))
+    }
+}
+
+/// Totals a manifest one line at a time, so inputs of any size run in constant memory.
+pub fn stream_totals(mut reader: impl BufRead, formula: &Formula) -> common::Result<Totals> {
+    let mut totals = Totals::default();
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        number += 1;
+        let code = strip_comment(&line);
+        if !code.is_empty() {
+            totals.add(formula, parse_module(code, number)?.mass)?;
+        }
+    }
+    Ok(totals)
+}
+
+/// The fuel one module needs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleFuel {
+    pub name: String,
+    pub mass: i128,
+    /// Fuel for the module's own mass.
+    pub fuel: i128,
+    /// Fuel including the fuel needed to carry the fuel.
+    pub total: i128,
+    pub iterations: u32,
+}
+
+pub struct Plan {
+    pub formula: Formula,
+    pub modules: Vec<ModuleFuel>,
+    pub totals: Totals,
+}
+
+impl Plan {
+    pub fn new(formula: Formula, modules: &[Module]) -> common::Result<Plan> {
+        let mut totals = Totals::default();
+        let mut breakdown = Vec::new();
+        for module in modules {
+            let (total, iterations) = totals.add(&formula, module.mass)?;
+            breakdown.push(ModuleFuel {
+                name: module.name.clone(),
+                mass: module.mass,
+                fuel: formula.fuel(module.mass),
+                total,
+                iterations,
+            });
+        }
+        Ok(Plan { formula, modules: breakdown, totals })
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("name,mass,fuel,total,iterations\n");
+        for m in &self.modules {
+            out += &format!("{},{},{},{},{}\n", csv_field(&m.name), m.mass, m.fuel, m.total, m.iterations);
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        let modules: Vec<String> = self.modules.iter().map(|m| format!(
+            "    {{\"name\": {}, \"mass\": {}, \"fuel\": {}, \"total\": {}, \"iterations\": {}}}",
+            json_string(&m.name), m.mass, m.fuel, m.total, m.iterations,
+        )).collect();
+        format!(
+            "{{\n  \"formula\": {{\"divisor\": {}, \"subtrahend\": {}, \"rounding\": \"{}\"}},\n  \"fuel\": {},\n  \"total\": {},\n  \"modules\": [\n{}\n  ]\n}}\n",
+            self.formula.divisor, self.formula.subtrahend, format!("{:?}", self.formula.rounding).to_lowercase(),
+            self.totals.fuel, self.totals.total, modules.join(",\n"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounding() {
+        assert_eq!("nearest".parse::<Rounding>(), Ok(Rounding::Nearest));
+        assert_eq!("up".parse::<Rounding>().unwrap_err(), "invalid rounding `up`, expected floor, ceil or nearest");
+
+        let fuel = |rounding, mass| Formula::new(3, 2, rounding).unwrap().fuel(mass);
+        assert_eq!([12, 13, 14].iter().map(|x| fuel(Rounding::Floor, *x)).collect::<Vec<_>>(), vec![2, 2, 2]);
+        assert_eq!([12, 13, 14].iter().map(|x| fuel(Rounding::Ceil, *x)).collect::<Vec<_>>(), vec![2, 3, 3]);
+        assert_eq!([12, 13, 14].iter().map(|x| fuel(Rounding::Nearest, *x)).collect::<Vec<_>>(), vec![2, 2, 3]);
+        assert_eq!(fuel(Rounding::Floor, 5), 0);
+        assert_eq!(fuel(Rounding::Nearest, -4), 0);
+    }
+
+    #[test]
+    fn formula() {
+        assert_eq!(Formula::new(3, 2, Rounding::Floor), Ok(Formula::default()));
+        assert_eq!(Formula::new(0, 2, Rounding::Floor).unwrap_err(), "divisor must be positive, got 0");
+        assert!(Formula::new(-3, 2, Rounding::Ceil).is_err());
+        assert_eq!(Formula::new(3, -1, Rounding::Floor).unwrap().fuel(0), 1);
+        assert_eq!(Formula::new(1, i64::MIN, Rounding::Floor).unwrap().fuel(i128::MAX), i128::MAX);
+    }
+
+    #[test]
+    fn increments() {
+        let formula = Formula::default();
+        assert_eq!(formula.increments(100756).collect::<Vec<_>>(), vec![33583, 11192, 3728, 1240, 411, 135, 43, 12, 2]);
+        assert_eq!(formula.fuel_for_fuel(100756), Some((50346, 9)));
+        assert_eq!(formula.increments(8).count(), 0);
+        assert_eq!(formula.fuel_for_fuel(8), Some((0, 0)));
+
+        // Formulas that don't shrink the fuel stop after the first increment.
+        assert_eq!(Formula::new(1, 0, Rounding::Floor).unwrap().increments(50).collect::<Vec<_>>(), vec![50]);
+        assert_eq!(Formula::new(1, -1, Rounding::Floor).unwrap().increments(50).collect::<Vec<_>>(), vec![51]);
+        assert_eq!(Formula::new(1, i64::MIN, Rounding::Floor).unwrap().fuel_for_fuel(i128::MAX), Some((i128::MAX, 1)));
+    }
+
+    #[test]
+    fn streams() {
+        let formula = Formula::default();
+        let totals = stream_totals("12\n# comment\nbooster 14\n\n1969".as_bytes(), &formula).unwrap();
+        assert_eq!(totals, Totals { modules: 3, fuel: 658, total: 970 });
+        let modules = parse_manifest("12\nbooster 14\n1969").unwrap();
+        assert_eq!(Plan::new(formula, &modules).unwrap().totals, totals);
+
+        assert_eq!(stream_totals("12\n\nx".as_bytes(), &formula).unwrap_err().to_string(),
+                   "line 3: invalid mass `x`: invalid digit found in string");
+        let huge = format!("{0}\n{0}\n{0}\n", i128::MAX);
+        assert_eq!(stream_totals(huge.as_bytes(), &formula).unwrap_err().to_string(),
+                   "fuel total overflowed after 2 modules");
+        assert!(Plan::new(formula, &parse_manifest(&huge).unwrap()).is_err());
+    }
+
+    #[test]
+    fn manifests() {
+        let modules = parse_manifest("# header\n12\nmain engine 1969  # big\n\n  14\n").unwrap();
+        let names: Vec<(&str, i128)> = modules.iter().map(|m| (m.name.as_str(), m.mass)).collect();
+        assert_eq!(names, vec![("module-2", 12), ("main engine", 1969), ("module-5", 14)]);
+        assert_eq!(parse_manifest("12\n\nbooster 1.5").unwrap_err().to_string(),
+                   "line 3: invalid mass `1.5`: invalid digit found in string");
+        assert!(parse_manifest("# nothing\n").unwrap().is_empty());
+
+        // Non-breaking and ideographic spaces are several bytes long.
+        let modules = parse_manifest("engine\u{a0}12\nbig\u{3000}booster\u{3000}1969").unwrap();
+        let names: Vec<(&str, i128)> = modules.iter().map(|m| (m.name.as_str(), m.mass)).collect();
+        assert_eq!(names, vec![("engine", 12), ("big\u{3000}booster", 1969)]);
+    }
+
+    #[test]
+    fn reports() {
+        let modules = parse_manifest("12\n\"fast\", \"light\" 1969").unwrap();
+        let plan = Plan::new(Formula::new(3, 2, Rounding::Ceil).unwrap(), &modules).unwrap();
+        assert_eq!(plan.to_csv(), "name,mass,fuel,total,iterations\n\
+                                   module-1,12,2,2,1\n\
+                                   \"\"\"fast\"\", \"\"light\"\"\",1969,655,971,5\n");
+        assert_eq!(plan.to_json(), r#"{
+  "formula": {"divisor": 3, "subtrahend": 2, "rounding": "ceil"},
+  "fuel": 657,
+  "total": 973,
+  "modules": [
+    {"name": "module-1", "mass": 12, "fuel": 2, "total": 2, "iterations": 1},
+    {"name": "\"fast\", \"light\"", "mass": 1969, "fuel": 655, "total": 971, "iterations": 5}
+  ]
+}
+"#);
+    }
+}
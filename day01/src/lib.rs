@@ -0,0 +1,51 @@
+use common::{Answer, Solution};
+
+use crate::fuel::{Formula, Module};
+
+pub mod fuel;
+
+/// The sum of `fuel` over the modules, or `None` if it overflows.
+fn sum(modules: &[Module], fuel: impl Fn(&Formula, i128) -> Option<i128>) -> Option<i128> {
+    let formula = Formula::default();
+    modules.iter().try_fold(0_i128, |sum, module| sum.checked_add(fuel(&formula, module.mass)?))
+}
+
+fn answer(sum: Option<i128>) -> Answer {
+    match sum {
+        Some(sum) => sum.into(),
+        None => Answer::Unsolved,
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const NAME: &'static str = "The Tyranny of the Rocket Equation";
+    type Input = Vec<Module>;
+
+    fn parse(input: &str) -> common::Result<Vec<Module>> {
+        fuel::parse_manifest(input)
+    }
+
+    fn part1(modules: &Vec<Module>) -> Answer {
+        answer(sum(modules, |formula, mass| Some(formula.fuel(mass))))
+    }
+
+    fn part2(modules: &Vec<Module>) -> Answer {
+        answer(sum(modules, |formula, mass| formula.fuel_for_fuel(mass).map(|(total, _)| total)))
+    }
+}
+
+common::examples!(Day01 {
+    mass_12: part1("12") == 2,
+    mass_14: part1("14") == 2,
+    mass_1969: part1("1969") == 654,
+    mass_100756: part1("100756") == 33583,
+    fuel_14: part2("14") == 2,
+    fuel_1969: part2("1969") == 966,
+    fuel_100756: part2("100756") == 50346,
+    huge_mass: part1("170141183460469231731687303715884105727") == "56713727820156410577229101238628035240",
+    huge_fuel: part1("170141183460469231731687303715884105727\n170141183460469231731687303715884105727\n170141183460469231731687303715884105727") == "170141183460469231731687303715884105720",
+    huge_fuel_for_fuel: part2("170141183460469231731687303715884105727\n170141183460469231731687303715884105727\n170141183460469231731687303715884105727") == Answer::Unsolved,
+});
@@ -0,0 +1,61 @@
+use common::{Answer, Solution};
+
+/// Runs the program with `noun` and `verb` in addresses 1 and 2. `None` if it can't finish.
+fn simulate(mem: &[i64], noun: i64, verb: i64) -> Option<i64> {
+    let mut mem = mem.to_vec();
+    *mem.get_mut(1)? = noun;
+    *mem.get_mut(2)? = verb;
+    let mut pos = 0;
+    loop {
+        let (a, b) = match mem[pos..] {
+            [99, ..] => break,
+            [_, a, b, _, ..] => (*mem.get(a as usize)?, *mem.get(b as usize)?),
+            _ => return None,
+        };
+        let value = match mem[pos] {
+            1 => a + b,
+            2 => a * b,
+            _ => return None,
+        };
+        let c = mem[pos + 3] as usize;
+        *mem.get_mut(c)? = value;
+        pos += 4;
+    }
+    Some(mem[0])
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const NAME: &'static str = "1202 Program Alarm";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        simulate(mem, 12, 2).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        let target = 19690720;
+        for a in 0..99 {
+            for b in 0..99 {
+                if simulate(mem, a, b) == Some(target) {
+                    return (a * 100 + b).into();
+                }
+            }
+        }
+        Answer::Unsolved
+    }
+}
+
+common::examples!(Day02 {
+    // 12 + 2, once the noun and verb are in place.
+    patched_add: part1("1,0,0,0,99,0,0,0,0,0,0,0,12") == 14,
+    noun_out_of_range: part1("1,9,10,3,2,3,11,0,99,30,40,50") == Answer::Unsolved,
+    finds_noun_and_verb: part2("1,0,0,0,99,19690000,720") == 506,
+    too_short: part2("99") == Answer::Unsolved,
+});
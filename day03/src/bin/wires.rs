@@ -0,0 +1,31 @@
+use std::env;
+use std::fs;
+
+use common::fail;
+
+const USAGE: &str = "usage: wires <input> [--output path.svg]";
+
+fn main() {
+    let mut input = None;
+    let mut output = None;
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = Some(args.next().unwrap_or_else(|| fail("--output needs a path")).clone()),
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => fail(USAGE),
+        }
+    }
+
+    let path = input.unwrap_or_else(|| fail(USAGE));
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    let paths = day03::parse_wires(&text).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+
+    let svg = day03::svg::render(&paths);
+    match output {
+        Some(output) => fs::write(&output, svg).unwrap_or_else(|e| fail(&format!("{}: {}", output, e))),
+        None => print!("{}", svg),
+    }
+}
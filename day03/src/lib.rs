@@ -0,0 +1,161 @@
+use std::collections::BTreeSet;
+
+use common::{Answer, Solution};
+use common::geom::{Direction, Point};
+use crate::path::WirePath;
+use crate::segment::{Intersection, Segment};
+
+pub mod path;
+pub mod segment;
+pub mod svg;
+pub mod sweep;
+
+/// The longest a wire may be. It bounds every coordinate, distance and step count well
+/// inside `i64`.
+const MAX_LENGTH: i64 = i64::MAX / 4;
+
+fn parse_segment(s: &str) -> common::Result<Point> {
+    let direction = match s.chars().next() {
+        Some('U') => Direction::Up,
+        Some('D') => Direction::Down,
+        Some('L') => Direction::Left,
+        Some('R') => Direction::Right,
+        _ => return Err(format!("invalid segment `{}`", s).into()),
+    };
+    let magnitude = s[1..].parse::<i64>()?;
+    if magnitude < 0 {
+        return Err(format!("invalid segment `{}`", s).into());
+    }
+
+    Ok(direction.delta() * magnitude)
+}
+
+pub fn parse_wire(str: &str) -> common::Result<Vec<Segment>> {
+    let segments = str.split(',').map(parse_segment);
+    let mut pos = Point::ORIGIN;
+    let mut length: i64 = 0;
+    let mut wire = Vec::new();
+    for (index, s) in segments.enumerate() {
+        let s = s?;
+        length = length.checked_add(s.x.abs() + s.y.abs()).filter(|&x| x <= MAX_LENGTH)
+            .ok_or_else(|| format!("segment {}: wire is longer than {}", index + 1, MAX_LENGTH))?;
+        wire.push(Segment::new(pos, pos + s));
+        pos += s;
+    }
+    Ok(wire)
+}
+
+/// One wire per non-blank line.
+pub fn parse_wires(input: &str) -> common::Result<Vec<WirePath>> {
+    input.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_wire(line.trim()).map(WirePath::new))
+        .collect()
+}
+
+/// The points of a crossing where the distance from the origin or the steps along both wires
+/// can be smallest. Both change linearly along an overlap except around the origin, so only
+/// the ends and the points either side of the origin need checking. If a wire first reaches
+/// some point of the overlap by another segment, that segment makes a crossing of its own. The
+/// origin itself, where every wire starts, never counts.
+fn candidates(isect: &Intersection) -> Vec<Point> {
+    let (lo, hi) = isect.ends();
+    let mut points = vec![lo, hi];
+    if let Intersection::Overlap(segment) = isect {
+        let unit = Point::new((hi.x - lo.x).signum(), (hi.y - lo.y).signum());
+        let nearest = segment.clamp(Point::ORIGIN);
+        points.extend(&[nearest, nearest - unit, nearest + unit]);
+    }
+    points.retain(|p| *p != Point::ORIGIN && isect.contains(*p));
+    points
+}
+
+/// A point where two or more wires cross.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Junction {
+    pub point: Point,
+    /// `(wire, steps to first reach the point)` for every wire through it.
+    pub steps: Vec<(usize, i64)>,
+}
+
+impl Junction {
+    /// The fewest steps two of the wires take between them to get here.
+    pub fn combined_steps(&self) -> i64 {
+        let mut steps: Vec<i64> = self.steps.iter().map(|x| x.1).collect();
+        steps.sort_unstable();
+        steps[0] + steps[1]
+    }
+}
+
+/// The junctions between any of the wires, ordered by point. Overlapping wires contribute the
+/// points given by `candidates` rather than every point they share.
+pub fn junctions(paths: &[WirePath]) -> Vec<Junction> {
+    let points: BTreeSet<Point> = sweep::crossings(paths).iter().flat_map(|x| candidates(&x.at)).collect();
+    points.into_iter().map(|point| {
+        let steps = paths.iter().enumerate()
+            .filter_map(|(w, path)| path.steps_to(point).map(|steps| (w, steps)))
+            .collect();
+        Junction { point, steps }
+    }).collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const NAME: &'static str = "Crossed Wires";
+    type Input = Vec<WirePath>;
+
+    /// One wire per line; the puzzle has two, but any number of them works.
+    fn parse(input: &str) -> common::Result<Vec<WirePath>> {
+        let wires = parse_wires(input)?;
+        if wires.len() < 2 {
+            return Err(format!("expected at least 2 wires, found {}", wires.len()).into());
+        }
+        Ok(wires)
+    }
+
+    fn part1(wires: &Vec<WirePath>) -> Answer {
+        match junctions(wires).iter().map(|x| x.point.manhattan(Point::ORIGIN)).min() {
+            Some(dist) => dist.into(),
+            None => Answer::Unsolved,
+        }
+    }
+
+    fn part2(wires: &Vec<WirePath>) -> Answer {
+        match junctions(wires).iter().map(Junction::combined_steps).min() {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+common::examples!(Day03 {
+    distance_small: part1("R8,U5,L5,D3\nU7,R6,D4,L4") == 6,
+    distance_1: part1("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83") == 159,
+    distance_2: part1("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7") == 135,
+    steps_small: part2("R8,U5,L5,D3\nU7,R6,D4,L4") == 30,
+    steps_1: part2("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83") == 610,
+    steps_2: part2("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7") == 410,
+    overlap_distance: part1("R10,U1\nR5,D1") == 1,
+    overlap_steps: part2("R10,U1\nR5,D1") == 2,
+    overlap_middle: part1("L3,D1,R6\nR3,D1,L6") == 1,
+    overlap_middle_steps: part2("L3,D1,R6\nR3,D1,L6") == 14,
+    three_wires_distance: part1("R8,U5,L5,D3\nU7,R6,D4,L4\nD2,L2,U4,R4") == 2,
+    three_wires_steps: part2("R8,U5,L5,D3\nU7,R6,D4,L4\nD2,L2,U4,R4") == 12,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_overlong_wires() {
+        let error = |wire| parse_wire(wire).err().unwrap().to_string();
+        assert_eq!(error("R9223372036854775807"), "segment 1: wire is longer than 2305843009213693951");
+        assert_eq!(error("U5,L2305843009213693947"), "segment 2: wire is longer than 2305843009213693951");
+        assert_eq!(error("R2305843009213693951,L2305843009213693951"), "segment 2: wire is longer than 2305843009213693951");
+        assert_eq!(error("R1,D-3"), "invalid segment `D-3`");
+        assert!(parse_wire("U5,L2305843009213693946").is_ok());
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::{BTreeSet, HashMap};
+
+use common::geom::Point;
+use crate::segment::Segment;
+
+/// A stretch `[start, end)` of a line that a wire first reaches along segment `index`.
+type Piece = (i64, i64, usize);
+
+/// A wire's segments, indexed so the steps to any point can be found quickly.
+pub struct WirePath {
+    segments: Vec<Segment>,
+    /// Steps taken before each segment starts.
+    steps: Vec<i64>,
+    /// For each `y`, the pieces of the horizontal segments on it, ordered by `x`.
+    rows: HashMap<i64, Vec<Piece>>,
+    /// For each `x`, the pieces of the vertical (and zero-length) segments on it, ordered by `y`.
+    columns: HashMap<i64, Vec<Piece>>,
+}
+
+fn unit(segment: &Segment) -> Point {
+    let delta = segment.p1 - segment.p0;
+    Point::new(delta.x.signum(), delta.y.signum())
+}
+
+/// Splits a line covered by `(from, to, index)` spans into pieces labelled with the lowest
+/// index covering them, merging neighbours with the same label.
+fn pieces(spans: &[(i64, i64, usize)]) -> Vec<Piece> {
+    let mut events: Vec<(i64, bool, usize)> = Vec::new();
+    for &(from, to, index) in spans {
+        events.push((from, true, index));
+        events.push((to + 1, false, index));
+    }
+    events.sort();
+
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut active = BTreeSet::new();
+    let mut i = 0;
+    while i < events.len() {
+        let at = events[i].0;
+        while i < events.len() && events[i].0 == at {
+            let (_, enter, index) = events[i];
+            if enter {
+                active.insert(index);
+            } else {
+                active.remove(&index);
+            }
+            i += 1;
+        }
+        if let (Some(&first), Some(&(next, _, _))) = (active.iter().next(), events.get(i)) {
+            match pieces.last_mut() {
+                Some(last) if last.1 == at && last.2 == first => last.1 = next,
+                _ => pieces.push((at, next, first)),
+            }
+        }
+    }
+    pieces
+}
+
+fn lookup(pieces: Option<&Vec<Piece>>, at: i64) -> Option<usize> {
+    let pieces = pieces?;
+    let i = pieces.partition_point(|piece| piece.1 <= at);
+    pieces.get(i).filter(|piece| piece.0 <= at).map(|piece| piece.2)
+}
+
+impl WirePath {
+    pub fn new(segments: Vec<Segment>) -> WirePath {
+        let mut steps = Vec::with_capacity(segments.len());
+        let mut total = 0;
+        let mut rows: HashMap<i64, Vec<(i64, i64, usize)>> = HashMap::new();
+        let mut columns: HashMap<i64, Vec<(i64, i64, usize)>> = HashMap::new();
+        for (index, s) in segments.iter().enumerate() {
+            steps.push(total);
+            total += s.len();
+            if s.p0.y == s.p1.y && !s.is_empty() {
+                rows.entry(s.p0.y).or_default().push((s.p0.x.min(s.p1.x), s.p0.x.max(s.p1.x), index));
+            } else {
+                columns.entry(s.p0.x).or_default().push((s.p0.y.min(s.p1.y), s.p0.y.max(s.p1.y), index));
+            }
+        }
+
+        WirePath {
+            segments,
+            steps,
+            rows: rows.into_iter().map(|(y, spans)| (y, pieces(&spans))).collect(),
+            columns: columns.into_iter().map(|(x, spans)| (x, pieces(&spans))).collect(),
+        }
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Steps from the start to the end of the wire.
+    pub fn len(&self) -> i64 {
+        match self.segments.last() {
+            Some(last) => self.steps[self.steps.len() - 1] + last.len(),
+            None => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Steps along the wire to first reach `point`, or `None` if it never gets there.
+    pub fn steps_to(&self, point: Point) -> Option<i64> {
+        let row = lookup(self.rows.get(&point.y), point.x);
+        let column = lookup(self.columns.get(&point.x), point.y);
+        let index = match (row, column) {
+            (Some(a), Some(b)) => a.min(b),
+            (a, b) => a.or(b)?,
+        };
+        Some(self.steps[index] + self.segments[index].p0.manhattan(point))
+    }
+
+    /// Every point the wire passes through in order, starting at its first point. Points it
+    /// comes back to appear again, so the position in the sequence is the number of steps taken.
+    pub fn cells(&self) -> impl Iterator<Item=Point> + '_ {
+        let start = self.segments.first().map(|s| s.p0);
+        start.into_iter().chain(self.segments.iter().flat_map(|s| {
+            let unit = unit(s);
+            (1..=s.len()).map(move |k| s.p0 + unit * k)
+        }))
+    }
+}
+
+impl AsRef<[Segment]> for WirePath {
+    fn as_ref(&self) -> &[Segment] {
+        &self.segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{junctions, parse_wire};
+
+    const WIRES: [&str; 3] = [
+        "R5,U1,R5,D1,L20,U3,R4,D5,L0,D2",
+        "U1,R20,D1,L20,D2,R3,U6",
+        "L3,D1,R6,U2,L4,D3,R2,U3,L1",
+    ];
+
+    fn walk(path: &WirePath) -> HashMap<Point, i64> {
+        let mut first = HashMap::new();
+        for (steps, point) in path.cells().enumerate() {
+            first.entry(point).or_insert(steps as i64);
+        }
+        first
+    }
+
+    #[test]
+    fn steps_match_walking() {
+        for wire in WIRES.iter() {
+            let path = WirePath::new(parse_wire(wire).unwrap());
+            let walked = walk(&path);
+            assert_eq!(path.cells().count() as i64, path.len() + 1);
+            for (point, steps) in &walked {
+                assert_eq!(path.steps_to(*point), Some(*steps), "{} at {:?}", wire, point);
+            }
+            assert_eq!(path.steps_to(Point::new(100, 100)), None);
+        }
+    }
+
+    #[test]
+    fn combined_steps_match_walking() {
+        let paths: Vec<WirePath> = WIRES.iter().map(|x| WirePath::new(parse_wire(x).unwrap())).collect();
+        let walks: Vec<HashMap<Point, i64>> = paths.iter().map(walk).collect();
+        for i in 0..paths.len() {
+            for j in i + 1..paths.len() {
+                let pair = [WirePath::new(parse_wire(WIRES[i]).unwrap()), WirePath::new(parse_wire(WIRES[j]).unwrap())];
+                let walked = walks[i].iter()
+                    .filter(|(point, _)| **point != Point::ORIGIN)
+                    .filter_map(|(point, a)| walks[j].get(point).map(|b| a + b))
+                    .min();
+                let found = junctions(&pair).iter().map(|x| x.combined_steps()).min();
+                assert_eq!(found, walked, "{} and {}", WIRES[i], WIRES[j]);
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::cmp::{max, min};
+
+use common::geom::Point;
+
+/// A horizontal or vertical run of wire from `p0` to `p1`, both ends included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Segment {
+    pub p0: Point,
+    pub p1: Point,
+}
+
+/// Where two segments meet: a single point, or the stretch they share when they're collinear.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Intersection {
+    Point(Point),
+    /// Runs from the lower to the higher coordinate, whichever way the segments point.
+    Overlap(Segment),
+}
+
+impl Segment {
+    pub fn new(p0: Point, p1: Point) -> Segment {
+        debug_assert!(p0.x == p1.x || p0.y == p1.y, "segment {:?} -> {:?} isn't axis-aligned", p0, p1);
+        Segment { p0, p1 }
+    }
+
+    pub fn len(&self) -> i64 {
+        self.p0.manhattan(self.p1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.p0 == self.p1
+    }
+
+    fn min(&self) -> Point {
+        Point::new(min(self.p0.x, self.p1.x), min(self.p0.y, self.p1.y))
+    }
+
+    fn max(&self) -> Point {
+        Point::new(max(self.p0.x, self.p1.x), max(self.p0.y, self.p1.y))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        let (lo, hi) = (self.min(), self.max());
+        lo.x <= point.x && point.x <= hi.x && lo.y <= point.y && point.y <= hi.y
+    }
+
+    /// The point of the segment nearest to `point`.
+    pub fn clamp(&self, point: Point) -> Point {
+        let (lo, hi) = (self.min(), self.max());
+        Point::new(point.x.max(lo.x).min(hi.x), point.y.max(lo.y).min(hi.y))
+    }
+
+    /// An axis-aligned segment is a box with no width or no height, so two of them meet exactly
+    /// where their boxes do.
+    pub fn intersect(&self, other: &Segment) -> Option<Intersection> {
+        let (a, b) = (self.min(), self.max());
+        let (c, d) = (other.min(), other.max());
+        let lo = Point::new(max(a.x, c.x), max(a.y, c.y));
+        let hi = Point::new(min(b.x, d.x), min(b.y, d.y));
+        if lo.x > hi.x || lo.y > hi.y {
+            None
+        } else if lo == hi {
+            Some(Intersection::Point(lo))
+        } else {
+            Some(Intersection::Overlap(Segment::new(lo, hi)))
+        }
+    }
+}
+
+impl Intersection {
+    pub fn contains(&self, point: Point) -> bool {
+        match self {
+            Intersection::Point(p) => *p == point,
+            Intersection::Overlap(segment) => segment.contains(point),
+        }
+    }
+
+    pub fn ends(&self) -> (Point, Point) {
+        match self {
+            Intersection::Point(p) => (*p, *p),
+            Intersection::Overlap(segment) => (segment.p0, segment.p1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x0: i64, y0: i64, x1: i64, y1: i64) -> Segment {
+        Segment::new(Point::new(x0, y0), Point::new(x1, y1))
+    }
+
+    #[test]
+    fn crossing() {
+        let isect = segment(0, 5, 10, 5).intersect(&segment(3, 0, 3, 8));
+        assert_eq!(isect, Some(Intersection::Point(Point::new(3, 5))));
+    }
+
+    #[test]
+    fn touching_ends() {
+        let isect = segment(0, 0, 4, 0).intersect(&segment(4, 0, 4, -3));
+        assert_eq!(isect, Some(Intersection::Point(Point::new(4, 0))));
+        assert_eq!(segment(0, 0, 4, 0).intersect(&segment(5, 0, 9, 0)), None);
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        let isect = segment(10, 2, 0, 2).intersect(&segment(3, 2, 15, 2));
+        assert_eq!(isect, Some(Intersection::Overlap(segment(3, 2, 10, 2))));
+        assert_eq!(segment(0, 0, 0, 5).intersect(&segment(1, 0, 1, 5)), None);
+    }
+}
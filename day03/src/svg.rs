@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use common::geom::Point;
+use common::grid::Bounds;
+use crate::{junctions, sweep, Junction};
+use crate::path::WirePath;
+use crate::segment::Intersection;
+
+/// Wire colours, used in turn.
+const COLOURS: [&str; 6] = ["#1f77b4", "#d62728", "#2ca02c", "#9467bd", "#ff7f0e", "#17becf"];
+
+fn points(points: impl Iterator<Item=Point>) -> String {
+    points.map(|p| format!("{},{}", p.x, p.y)).collect::<Vec<_>>().join(" ")
+}
+
+/// Draws the wires over a white background with the origin as a black dot, every crossing in
+/// yellow, and labels on the crossing nearest the origin and the one the fewest combined steps
+/// away. The view box fits the wires, so the picture is the same size however long they are;
+/// lines and labels are sized relative to it.
+pub fn render(paths: &[WirePath]) -> String {
+    let mut bounds = Bounds::new(Point::ORIGIN.into());
+    for path in paths {
+        for s in path.segments() {
+            bounds.include(s.p0.into());
+            bounds.include(s.p1.into());
+        }
+    }
+    let extent = bounds.width().max(bounds.height());
+    let margin = (extent / 20).max(1);
+    let (x, y) = (bounds.min_x - margin, bounds.min_y - margin);
+    let (width, height) = (bounds.width() - 1 + 2 * margin, bounds.height() - 1 + 2 * margin);
+    let unit = extent as f64 / 400.0;
+
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="800" height="{}">"#,
+             x, y, width, height, 800 * i128::from(height) / i128::from(width)).unwrap();
+    writeln!(svg, r#"<rect x="{}" y="{}" width="{}" height="{}" fill="white"/>"#, x, y, width, height).unwrap();
+
+    for (i, path) in paths.iter().enumerate() {
+        let corners = path.segments().first().map(|s| s.p0).into_iter().chain(path.segments().iter().map(|s| s.p1));
+        writeln!(svg, r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.2}" stroke-linejoin="round"/>"#,
+                 points(corners), COLOURS[i % COLOURS.len()], unit).unwrap();
+    }
+
+    for crossing in sweep::crossings(paths) {
+        match crossing.at {
+            // Every wire starts at the origin, which is drawn on its own.
+            Intersection::Point(p) if p == Point::ORIGIN => continue,
+            Intersection::Point(p) => writeln!(svg, r#"<circle cx="{}" cy="{}" r="{:.2}" fill="gold" stroke="black" stroke-width="{:.2}"/>"#,
+                                               p.x, p.y, 2.0 * unit, unit / 4.0),
+            Intersection::Overlap(s) => writeln!(svg, r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="gold" stroke-opacity="0.8" stroke-width="{:.2}" stroke-linecap="round"/>"#,
+                                                 s.p0.x, s.p0.y, s.p1.x, s.p1.y, 3.0 * unit),
+        }.unwrap();
+    }
+    writeln!(svg, r#"<circle cx="0" cy="0" r="{:.2}" fill="black"/>"#, 2.5 * unit).unwrap();
+
+    let junctions = junctions(paths);
+    let nearest = junctions.iter().min_by_key(|x| x.point.manhattan(Point::ORIGIN));
+    let quickest = junctions.iter().min_by_key(|x| x.combined_steps());
+    let mut labels: Vec<(&Junction, String)> = Vec::new();
+    if let Some(junction) = nearest {
+        labels.push((junction, format!("distance {}", junction.point.manhattan(Point::ORIGIN))));
+    }
+    if let Some(junction) = quickest {
+        labels.push((junction, format!("steps {}", junction.combined_steps())));
+    }
+    for (i, (junction, text)) in labels.iter().enumerate() {
+        let p = junction.point;
+        writeln!(svg, r#"<circle cx="{}" cy="{}" r="{:.2}" fill="none" stroke="black" stroke-width="{:.2}"/>"#,
+                 p.x, p.y, 4.0 * unit, unit / 2.0).unwrap();
+        // The second label goes below the first in case both are on the same crossing.
+        writeln!(svg, r#"<text x="{:.2}" y="{:.2}" font-family="sans-serif" font-size="{:.2}">{}</text>"#,
+                 p.x as f64 + 5.0 * unit, p.y as f64 + (i as f64 * 12.0 - 2.0) * unit, 10.0 * unit, text).unwrap();
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_wires;
+
+    fn draw(input: &str) -> Vec<String> {
+        render(&parse_wires(input).unwrap()).lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn fits_the_view_box() {
+        let svg = draw("R8,U5,L5,D3\nU7,R6,D4,L4");
+        // 9 by 8 cells from (0, -7), with the smallest margin of 1.
+        assert_eq!(svg[0], r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-1 -8 10 9" width="800" height="720">"#);
+        assert_eq!(svg[1], r#"<rect x="-1" y="-8" width="10" height="9" fill="white"/>"#);
+
+        // 121 by 51 cells, so the margin is 121 / 20.
+        let svg = draw("R100,U50\nU40,R120");
+        assert_eq!(svg[0], r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-6 -56 132 62" width="800" height="375">"#);
+    }
+
+    #[test]
+    fn marks_crossings() {
+        let svg = draw("R8,U5,L5,D3\nU7,R6,D4,L4");
+        assert_eq!(svg[2], r##"<polyline points="0,0 8,0 8,-5 3,-5 3,-2" fill="none" stroke="#1f77b4" stroke-width="0.02" stroke-linejoin="round"/>"##);
+        let crossings: Vec<&String> = svg.iter().filter(|x| x.contains("fill=\"gold\"")).collect();
+        assert_eq!(crossings.len(), 2);
+        assert!(crossings[0].starts_with(r#"<circle cx="3" cy="-3" "#));
+        assert!(crossings[1].starts_with(r#"<circle cx="6" cy="-5" "#));
+        assert!(svg.contains(&r#"<text x="3.11" y="-3.04" font-family="sans-serif" font-size="0.22">distance 6</text>"#.to_string()));
+        assert!(svg.contains(&r#"<text x="6.11" y="-4.78" font-family="sans-serif" font-size="0.22">steps 30</text>"#.to_string()));
+        assert_eq!(svg.last().unwrap(), "</svg>");
+
+        // Overlaps are drawn as lines, and a lone wire has nothing to label.
+        let svg = draw("R5,U2\nU1,R1,D1,R6");
+        assert!(svg.iter().any(|x| x.starts_with(r#"<line x1="1" y1="0" x2="5" y2="0" stroke="gold""#)));
+        let svg = draw("R8,U5,L5,D3");
+        assert!(!svg.iter().any(|x| x.contains("gold") || x.contains("<text")));
+    }
+}
@@ -0,0 +1,94 @@
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::ops::RangeInclusive;
+
+use common::fail;
+use day04::count::{accepted, count};
+use day04::explain::explain;
+use day04::ranges::{merge, parse_ranges, union_count};
+use day04::rules::{Policy, Rule};
+
+const USAGE: &str = "usage: passwords <low>-<high> [--rule double|pair] [--output path]
+       passwords --explain <n> [--rule double|pair] [--output path]
+       passwords --ranges <path> [--output path]";
+
+/// Counts each range under both rule sets, then all of them together.
+fn report(ranges: &[RangeInclusive<u64>], out: &mut impl Write) -> io::Result<()> {
+    let (double, pair) = (Policy::Double.rule(), Policy::ExactPair.rule());
+
+    writeln!(out, "range,double,pair")?;
+    for range in ranges {
+        let (lo, hi) = (*range.start(), *range.end());
+        writeln!(out, "{}-{},{},{}", lo, hi, count(lo, hi, &*double), count(lo, hi, &*pair))?;
+    }
+    writeln!(out, "union,{},{}", union_count(ranges, &*double), union_count(ranges, &*pair))?;
+    eprintln!("{} ranges, {} after merging", ranges.len(), merge(ranges).len());
+    Ok(())
+}
+
+/// Every accepted number in the range, one per line.
+fn list(range: RangeInclusive<u64>, rule: &dyn Rule, out: &mut impl Write) -> io::Result<()> {
+    for num in accepted(*range.start(), *range.end(), rule) {
+        writeln!(out, "{}", num)?;
+    }
+    Ok(())
+}
+
+fn main() {
+    let mut range = None;
+    let mut explain_num = None;
+    let mut policy = None;
+    let mut output = None;
+    let mut ranges_path = None;
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().unwrap_or_else(|| fail(&format!("{} needs a value", flag))).clone();
+        match arg.as_str() {
+            "--explain" => explain_num = Some(value("--explain").parse::<u64>().unwrap_or_else(|e| fail(&format!("--explain: {}", e)))),
+            "--rule" => policy = match value("--rule").as_str() {
+                "double" => Some(Policy::Double),
+                "pair" => Some(Policy::ExactPair),
+                _ => fail("--rule must be double or pair"),
+            },
+            "--output" => output = Some(value("--output")),
+            "--ranges" => ranges_path = Some(value("--ranges")),
+            _ if range.is_none() => range = Some(arg.clone()),
+            _ => fail(USAGE),
+        }
+    }
+
+    if ranges_path.is_some() && (range.is_some() || policy.is_some() || explain_num.is_some()) {
+        fail("--ranges reads its ranges from the file and reports both rules");
+    }
+    if explain_num.is_some() && range.is_some() {
+        fail(USAGE);
+    }
+    let rule = policy.unwrap_or(Policy::Double).rule();
+    let ranges = ranges_path.map(|path| {
+        let text = fs::read_to_string(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+        parse_ranges(&text).unwrap_or_else(|e| fail(&format!("{}:\n{}", path, e)))
+    });
+
+    let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &output {
+        Some(path) => Box::new(File::create(path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)))),
+        None => Box::new(io::stdout()),
+    });
+    let written = match (ranges, explain_num) {
+        (Some(ranges), _) => report(&ranges, &mut out),
+        (None, Some(num)) => write!(out, "{}: {}", num, explain(num, &*rule)),
+        (None, None) => {
+            let range = range.unwrap_or_else(|| fail(USAGE));
+            let range = parse_ranges(&range).unwrap_or_else(|e| fail(&e.to_string()))[0].clone();
+            list(range, &*rule, &mut out)
+        }
+    };
+    match written.and_then(|_| out.flush()) {
+        Ok(()) => {}
+        // Most likely the reader went away, e.g. `| head`.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(e) => fail(&e.to_string()),
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use crate::rules::{digits, Rule};
+
+pub(crate) struct Counter<'a> {
+    rule: &'a dyn Rule,
+    memo: HashMap<(usize, Vec<u32>), u64>,
+}
+
+impl<'a> Counter<'a> {
+    pub(crate) fn new(rule: &'a dyn Rule) -> Counter<'a> {
+        Counter { rule, memo: HashMap::new() }
+    }
+
+    pub(crate) fn next(&self, state: &[u32], digit: u8) -> Vec<u32> {
+        let mut next = state.to_vec();
+        self.rule.push(&mut next, digit);
+        next
+    }
+
+    /// Ways to add `remaining` more digits, each anything from 0 to 9, to reach an accepted
+    /// number.
+    pub(crate) fn complete(&mut self, remaining: usize, state: &[u32]) -> u64 {
+        if remaining == 0 {
+            return self.rule.accepts(state) as u64;
+        }
+        if let Some(&count) = self.memo.get(&(remaining, state.to_vec())) {
+            return count;
+        }
+        let count = (0..=9).map(|digit| {
+            let next = self.next(state, digit);
+            self.complete(remaining - 1, &next)
+        }).sum();
+        self.memo.insert((remaining, state.to_vec()), count);
+        count
+    }
+
+    /// Numbers from 1 to `n` that the rule accepts.
+    fn up_to(&mut self, n: u64) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+        let digits = digits(n);
+        let len = digits.len();
+        let mut state = vec![0; self.rule.width()];
+        self.rule.start(&mut state);
+
+        // Everything shorter than `n`.
+        let mut count = 0;
+        for shorter in 1..len {
+            for first in 1..=9 {
+                let next = self.next(&state, first);
+                count += self.complete(shorter - 1, &next);
+            }
+        }
+
+        // Same length: follow `n`'s digits, and at each position also count the numbers that
+        // go below it there.
+        for (i, &bound) in digits.iter().enumerate() {
+            let lowest = if i == 0 { 1 } else { 0 };
+            for digit in lowest..bound {
+                let next = self.next(&state, digit);
+                count += self.complete(len - i - 1, &next);
+            }
+            self.rule.push(&mut state, bound);
+        }
+        count + self.rule.accepts(&state) as u64
+    }
+
+    pub(crate) fn start(&self) -> Vec<u32> {
+        let mut state = vec![0; self.rule.width()];
+        self.rule.start(&mut state);
+        state
+    }
+}
+
+/// Counts the numbers in `lo..=hi` that the rule accepts, in time that depends on the number of
+/// digits and how much state the rule keeps rather than the size of the range.
+pub fn count(lo: u64, hi: u64, rule: &dyn Rule) -> u64 {
+    if lo > hi {
+        return 0;
+    }
+    let zero = (lo == 0 && rule.check(0)) as u64;
+    let mut counter = Counter::new(rule);
+    counter.up_to(hi) - if lo == 0 { 0 } else { counter.up_to(lo - 1) } + zero
+}
+
+/// A prefix being extended by `Accepted`.
+struct Frame {
+    state: Vec<u32>,
+    value: u64,
+    /// Digits in the prefix.
+    len: usize,
+    /// The next digit to try after it.
+    digit: u8,
+    /// Whether the prefix matches the lower or upper bound so far.
+    at_low: bool,
+    at_high: bool,
+}
+
+/// The numbers the rule accepts, in increasing order. See `accepted`.
+pub struct Accepted<'a> {
+    counter: Counter<'a>,
+    zero: bool,
+    lo: u64,
+    hi: u64,
+    /// The number of digits being enumerated, and the bounds for that many digits.
+    len: usize,
+    low: Vec<u8>,
+    high: Vec<u8>,
+    stack: Vec<Frame>,
+}
+
+impl Accepted<'_> {
+    /// Moves on to numbers one digit longer, or returns false past the end.
+    fn next_length(&mut self) -> bool {
+        self.len += 1;
+        if self.lo > self.hi || self.len > digits(self.hi).len() {
+            return false;
+        }
+        let mut low = vec![0; self.len];
+        low[0] = 1;
+        self.low = if digits(self.lo).len() == self.len { digits(self.lo) } else { low };
+        self.high = if digits(self.hi).len() == self.len { digits(self.hi) } else { vec![9; self.len] };
+        let state = self.counter.start();
+        self.stack.push(Frame { state, value: 0, len: 0, digit: self.low[0], at_low: true, at_high: true });
+        true
+    }
+}
+
+impl Iterator for Accepted<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.zero {
+            self.zero = false;
+            return Some(0);
+        }
+        loop {
+            if self.stack.is_empty() && !self.next_length() {
+                return None;
+            }
+            let frame = self.stack.last_mut().unwrap();
+            let top = if frame.at_high { self.high[frame.len] } else { 9 };
+            if frame.digit > top {
+                self.stack.pop();
+                continue;
+            }
+
+            let digit = frame.digit;
+            frame.digit += 1;
+            let at_low = frame.at_low && digit == self.low[frame.len];
+            let at_high = frame.at_high && digit == self.high[frame.len];
+            let len = frame.len + 1;
+            let value = frame.value * 10 + digit as u64;
+            let state = self.counter.next(&frame.state, digit);
+
+            let remaining = self.len - len;
+            if remaining == 0 {
+                if self.counter.rule.accepts(&state) {
+                    return Some(value);
+                }
+            } else if at_low || at_high || self.counter.complete(remaining, &state) > 0 {
+                let digit = if at_low { self.low[len] } else { 0 };
+                self.stack.push(Frame { state, value, len, digit, at_low, at_high });
+            }
+        }
+    }
+}
+
+/// The numbers in `lo..=hi` that the rule accepts, in increasing order. They're found by going
+/// through the digits depth first, skipping any prefix the counting engine says can't lead
+/// anywhere, so sparse ranges are quick to go through and little is kept in memory.
+pub fn accepted(lo: u64, hi: u64, rule: &dyn Rule) -> Accepted<'_> {
+    let zero = lo == 0 && rule.check(0);
+    let lo = lo.max(1);
+    Accepted {
+        counter: Counter::new(rule),
+        zero,
+        lo,
+        hi,
+        len: digits(lo).len() - 1,
+        low: Vec::new(),
+        high: Vec::new(),
+        stack: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::*;
+
+    /// The original checker, kept to test the puzzle's policies against: whether a number
+    /// passes the first and the second part's rules.
+    fn is_valid(mut num: u64) -> (bool, bool) {
+        let mut last_digit = 10;
+        let mut cond1 = false;
+        let mut cond2 = true;
+        let mut cond3 = false;
+
+        let mut span = 0;
+
+        while num > 0 {
+            let digit = num % 10;
+
+            if digit == last_digit {
+                span += 1;
+                if span == 2 {
+                    cond1 = true;
+                }
+            } else {
+                if span == 2 {
+                    cond3 = true;
+                }
+                span = 1;
+            }
+
+            if digit > last_digit {
+                cond2 = false;
+                break;
+            }
+
+            last_digit = digit;
+            num /= 10;
+        }
+
+        if span == 2 {
+            cond3 = true;
+        }
+
+        ((cond1 && cond2), (cond3 && cond2))
+    }
+
+    #[test]
+    fn policies_match_original() {
+        let (double, pair) = (Policy::Double.rule(), Policy::ExactPair.rule());
+        for num in 100_000..=200_000 {
+            let (valid1, valid2) = is_valid(num);
+            assert_eq!((double.check(num), pair.check(num)), (valid1, valid2), "{}", num);
+        }
+        for &(lo, hi) in [(1, 99_999), (108457, 562041), (1_000_000, 3_000_000)].iter() {
+            let valid: Vec<(bool, bool)> = (lo..=hi).map(is_valid).collect();
+            assert_eq!(count(lo, hi, &*double), valid.iter().filter(|x| x.0).count() as u64, "{}-{}", lo, hi);
+            assert_eq!(count(lo, hi, &*pair), valid.iter().filter(|x| x.1).count() as u64, "{}-{}", lo, hi);
+        }
+    }
+
+    fn brute_force(lo: u64, hi: u64, rule: &dyn Rule) -> u64 {
+        (lo..=hi).filter(|x| rule.check(*x)).count() as u64
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Policy::Double.rule(),
+            Policy::ExactPair.rule(),
+            Box::new(Not(Box::new(NonDecreasing))),
+            Box::new(Or(vec![Box::new(DigitCount { digit: 7, min: 2, max: 3 }), Box::new(HasRunOfExactly(3))])),
+            Box::new(And(vec![Box::new(Not(Box::new(HasRunOfAtLeast(2)))), Box::new(DigitCount { digit: 0, min: 1, max: 1 })])),
+        ];
+        let ranges = [(0, 0), (0, 30000), (1, 9), (11, 11), (99990, 100200), (108457, 112041), (111110, 123456), (5, 4)];
+        for (i, rule) in rules.iter().enumerate() {
+            for &(lo, hi) in ranges.iter() {
+                assert_eq!(count(lo, hi, &**rule), brute_force(lo, hi, &**rule), "rule {} over {}-{}", i, lo, hi);
+            }
+        }
+    }
+
+    #[test]
+    fn enumerates_in_order() {
+        let rules: Vec<Box<dyn Rule>> = vec![Policy::ExactPair.rule(), Box::new(Not(Box::new(HasRunOfAtLeast(2))))];
+        for rule in &rules {
+            for &(lo, hi) in [(0, 2000), (1000, 1000), (99990, 100200), (5, 4)].iter() {
+                let expected: Vec<u64> = (lo..=hi).filter(|x| rule.check(*x)).collect();
+                assert_eq!(accepted(lo, hi, &**rule).collect::<Vec<_>>(), expected);
+            }
+        }
+        let first = accepted(500_000_000_000_000_000, u64::MAX, &*Policy::Double.rule()).take(3).collect::<Vec<_>>();
+        assert_eq!(first, vec![555_555_555_555_555_555, 555_555_555_555_555_556, 555_555_555_555_555_557]);
+    }
+
+    #[test]
+    fn long_numbers() {
+        // Every non-decreasing number of 10 or more digits repeats one: C(n + 8, 8) of them.
+        let double = Policy::Double.rule();
+        assert_eq!(count(10_000_000_000, 99_999_999_999, &*double), 75582);
+        assert_eq!(count(100_000_000_000_000_000, 999_999_999_999_999_999, &*double), 1562275);
+        assert!(count(0, u64::MAX, &*double) > count(0, 9_999_999_999_999_999_999, &*double));
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use crate::count::Counter;
+use crate::rules::{digits, Rule};
+
+/// How a rule, and each rule it's made of, decided on a number.
+pub struct Explanation {
+    pub rule: String,
+    pub accepted: bool,
+    /// How many digits from the left it took before no choice of the rest could change the
+    /// outcome.
+    pub decided_at: usize,
+    pub children: Vec<Explanation>,
+}
+
+pub fn explain(num: u64, rule: &dyn Rule) -> Explanation {
+    let digits = digits(num);
+    let mut counter = Counter::new(rule);
+    let mut state = counter.start();
+    let mut decided_at = digits.len();
+    for (i, &digit) in digits.iter().enumerate() {
+        state = counter.next(&state, digit);
+        let remaining = digits.len() - i - 1;
+        let count = counter.complete(remaining, &state);
+        if count == 0 || count == 10_u64.pow(remaining as u32) {
+            decided_at = i + 1;
+            break;
+        }
+    }
+
+    Explanation {
+        rule: rule.describe(),
+        accepted: rule.check(num),
+        decided_at,
+        children: rule.children().into_iter().map(|child| explain(num, child)).collect(),
+    }
+}
+
+impl Explanation {
+    fn write(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        let outcome = if self.accepted { "accepted" } else { "rejected" };
+        writeln!(f, "{:indent$}{}: {} at digit {}", "", self.rule, outcome, self.decided_at, indent = depth * 2)?;
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::Policy;
+
+    #[test]
+    fn decided_digits() {
+        let explanation = explain(223450, &*Policy::Double.rule());
+        assert!(!explanation.accepted);
+        assert_eq!(explanation.decided_at, 6);
+        let children: Vec<(bool, usize)> = explanation.children.iter().map(|x| (x.accepted, x.decided_at)).collect();
+        assert_eq!(children, vec![(false, 6), (true, 2)]);
+
+        // Nothing after a decrease can fix it.
+        assert_eq!(explain(1211111, &*Policy::ExactPair.rule()).decided_at, 3);
+    }
+}
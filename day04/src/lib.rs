@@ -0,0 +1,42 @@
+use std::ops::RangeInclusive;
+use common::{Answer, Solution};
+use crate::ranges::{parse_ranges, union_count};
+use crate::rules::Policy;
+
+pub mod count;
+pub mod explain;
+pub mod ranges;
+pub mod rules;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const NAME: &'static str = "Secure Container";
+    type Input = Vec<RangeInclusive<u64>>;
+
+    /// One `<low>-<high>` range per line, both ends included; the puzzle has just one.
+    fn parse(input: &str) -> common::Result<Vec<RangeInclusive<u64>>> {
+        parse_ranges(input)
+    }
+
+    fn part1(ranges: &Vec<RangeInclusive<u64>>) -> Answer {
+        union_count(ranges, &*Policy::Double.rule()).into()
+    }
+
+    fn part2(ranges: &Vec<RangeInclusive<u64>>) -> Answer {
+        union_count(ranges, &*Policy::ExactPair.rule()).into()
+    }
+}
+
+common::examples!(Day04 {
+    repeated_ones: part1("111111-111111") == 1,
+    decreasing: part1("223450-223450") == 0,
+    no_double: part1("123789-123789") == 0,
+    all_pairs: part2("112233-112233") == 1,
+    larger_group: part2("123444-123444") == 0,
+    group_and_pair: part2("111122-111122") == 1,
+    inclusive_end: part1("111110-111111") == 1,
+    overlapping: part1("111111-111115\n111113-111120") == 9,
+    mixed_lengths: part1("10-99\n50-999") == 90,
+});
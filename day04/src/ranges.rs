@@ -0,0 +1,77 @@
+use std::ops::RangeInclusive;
+
+use crate::count::count;
+use crate::rules::Rule;
+
+fn parse_range(line: &str) -> Result<RangeInclusive<u64>, String> {
+    let (lo, hi) = line.split_once('-').ok_or_else(|| format!("expected `<low>-<high>`, found `{}`", line))?;
+    let bound = |x: &str| x.trim().parse::<u64>().map_err(|e| format!("invalid bound `{}`: {}", x.trim(), e));
+    let (lo, hi) = (bound(lo)?, bound(hi)?);
+    if lo > hi {
+        return Err(format!("low bound {} is above high bound {}", lo, hi));
+    }
+    Ok(lo..=hi)
+}
+
+/// Parses one `<low>-<high>` range per line, both ends included. Blank lines are skipped. Every
+/// malformed line is reported, not just the first.
+pub fn parse_ranges(text: &str) -> common::Result<Vec<RangeInclusive<u64>>> {
+    let mut ranges = Vec::new();
+    let mut errors = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_range(line.trim()) {
+            Ok(range) => ranges.push(range),
+            Err(e) => errors.push(format!("line {}: {}", index + 1, e)),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors.join("\n").into());
+    }
+    if ranges.is_empty() {
+        return Err("no ranges".into());
+    }
+    Ok(ranges)
+}
+
+/// The same numbers as `ranges`, as sorted ranges that don't overlap or touch.
+pub fn merge(ranges: &[RangeInclusive<u64>]) -> Vec<RangeInclusive<u64>> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_by_key(|x| *x.start());
+    let mut merged: Vec<RangeInclusive<u64>> = Vec::new();
+    for range in sorted {
+        match merged.last_mut() {
+            Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                *last = *last.start()..=*last.end().max(range.end());
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// How many numbers in any of the ranges the rule accepts, counting each number once.
+pub fn union_count(ranges: &[RangeInclusive<u64>], rule: &dyn Rule) -> u64 {
+    merge(ranges).iter().map(|x| count(*x.start(), *x.end(), rule)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_overlaps() {
+        let ranges = parse_ranges("30-40\n\n1-5\n6-9\n35-50\n100-100\n").unwrap();
+        assert_eq!(merge(&ranges), vec![1..=9, 30..=50, 100..=100]);
+    }
+
+    #[test]
+    fn reports_every_bad_line() {
+        let error = parse_ranges("1-5\n7\n9-x\n10-2\n-3\n").unwrap_err().to_string();
+        let lines: Vec<&str> = error.lines().map(|x| x.split(':').next().unwrap()).collect();
+        assert_eq!(lines, vec!["line 2", "line 3", "line 4", "line 5"]);
+        assert!(parse_ranges("\n").is_err());
+    }
+}
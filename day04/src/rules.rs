@@ -0,0 +1,321 @@
+/// A condition on the decimal digits of a number, read from the left as a state machine. The
+/// state is a few numbers, so the counting engine can tell when two prefixes behave the same.
+pub trait Rule {
+    /// How many numbers of state the rule keeps.
+    fn width(&self) -> usize;
+    /// Sets up `state`, `width` numbers long, for a number with no digits yet.
+    fn start(&self, state: &mut [u32]);
+    /// Updates `state` for the next digit.
+    fn push(&self, state: &mut [u32], digit: u8);
+    fn accepts(&self, state: &[u32]) -> bool;
+
+    /// What the rule asks for, in a few words.
+    fn describe(&self) -> String;
+
+    /// The rules this one is made of, if any.
+    fn children(&self) -> Vec<&dyn Rule> {
+        Vec::new()
+    }
+
+    /// Whether `num`, without leading zeros, satisfies the rule.
+    fn check(&self, num: u64) -> bool {
+        let mut state = vec![0; self.width()];
+        self.start(&mut state);
+        for digit in digits(num) {
+            self.push(&mut state, digit);
+        }
+        self.accepts(&state)
+    }
+}
+
+pub fn digits(num: u64) -> Vec<u8> {
+    num.to_string().bytes().map(|b| b - b'0').collect()
+}
+
+/// Stands for "no digit yet" and, in a rule that's already settled, "doesn't matter".
+const NONE: u32 = 10;
+
+/// No digit is smaller than the one before it.
+pub struct NonDecreasing;
+
+impl Rule for NonDecreasing {
+    fn width(&self) -> usize { 2 }
+
+    /// `[last digit, failed]`
+    fn start(&self, state: &mut [u32]) {
+        state.copy_from_slice(&[0, 0]);
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        if state[1] == 1 || (digit as u32) < state[0] {
+            state.copy_from_slice(&[NONE, 1]);
+        } else {
+            state[0] = digit as u32;
+        }
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        state[1] == 0
+    }
+
+    fn describe(&self) -> String {
+        "digits never decrease".to_string()
+    }
+}
+
+/// Some digit is repeated at least this many times in a row.
+pub struct HasRunOfAtLeast(pub u32);
+
+impl Rule for HasRunOfAtLeast {
+    fn width(&self) -> usize { 3 }
+
+    /// `[last digit, length of its run, satisfied]`
+    fn start(&self, state: &mut [u32]) {
+        state.copy_from_slice(&[NONE, 0, (self.0 == 0) as u32]);
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        if state[2] == 1 {
+            return;
+        }
+        if digit as u32 == state[0] {
+            state[1] += 1;
+        } else {
+            state[0] = digit as u32;
+            state[1] = 1;
+        }
+        if state[1] >= self.0 {
+            state.copy_from_slice(&[NONE, 0, 1]);
+        }
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        state[2] == 1
+    }
+
+    fn describe(&self) -> String {
+        format!("a run of at least {} of a digit", self.0)
+    }
+}
+
+/// Some run of a repeated digit is exactly this long: not part of a longer run.
+pub struct HasRunOfExactly(pub u32);
+
+impl Rule for HasRunOfExactly {
+    fn width(&self) -> usize { 3 }
+
+    /// `[last digit, length of its run up to one more than needed, satisfied]`
+    fn start(&self, state: &mut [u32]) {
+        state.copy_from_slice(&[NONE, 0, 0]);
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        if state[2] == 1 {
+            return;
+        }
+        if digit as u32 == state[0] {
+            state[1] = (state[1] + 1).min(self.0 + 1);
+        } else if state[1] == self.0 && state[0] != NONE {
+            state.copy_from_slice(&[NONE, 0, 1]);
+        } else {
+            state[0] = digit as u32;
+            state[1] = 1;
+        }
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        state[2] == 1 || (state[1] == self.0 && state[0] != NONE)
+    }
+
+    fn describe(&self) -> String {
+        format!("a run of exactly {} of a digit", self.0)
+    }
+}
+
+/// `digit` appears between `min` and `max` times, inclusive.
+pub struct DigitCount {
+    pub digit: u8,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl Rule for DigitCount {
+    fn width(&self) -> usize { 1 }
+
+    /// `[times seen, up to one more than allowed]`
+    fn start(&self, state: &mut [u32]) {
+        state[0] = 0;
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        if digit == self.digit {
+            state[0] = (state[0] + 1).min(self.max.saturating_add(1));
+        }
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        self.min <= state[0] && state[0] <= self.max
+    }
+
+    fn describe(&self) -> String {
+        format!("{} to {} of the digit {}", self.min, self.max, self.digit)
+    }
+}
+
+pub struct Not(pub Box<dyn Rule>);
+
+impl Rule for Not {
+    fn width(&self) -> usize { self.0.width() }
+
+    fn start(&self, state: &mut [u32]) {
+        self.0.start(state)
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        self.0.push(state, digit)
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        !self.0.accepts(state)
+    }
+
+    fn describe(&self) -> String {
+        "not".to_string()
+    }
+
+    fn children(&self) -> Vec<&dyn Rule> {
+        vec![&*self.0]
+    }
+}
+
+/// Splits a combined state into the parts belonging to each rule.
+fn parts<'a>(rules: &'a [Box<dyn Rule>], mut state: &'a [u32]) -> Vec<(&'a dyn Rule, &'a [u32])> {
+    rules.iter().map(|rule| {
+        let (part, rest) = state.split_at(rule.width());
+        state = rest;
+        (&**rule, part)
+    }).collect()
+}
+
+fn start_all(rules: &[Box<dyn Rule>], mut state: &mut [u32]) {
+    for rule in rules {
+        let (part, rest) = state.split_at_mut(rule.width());
+        rule.start(part);
+        state = rest;
+    }
+}
+
+fn push_all(rules: &[Box<dyn Rule>], mut state: &mut [u32], digit: u8) {
+    for rule in rules {
+        let (part, rest) = state.split_at_mut(rule.width());
+        rule.push(part, digit);
+        state = rest;
+    }
+}
+
+pub struct And(pub Vec<Box<dyn Rule>>);
+
+impl Rule for And {
+    fn width(&self) -> usize { self.0.iter().map(|x| x.width()).sum() }
+
+    fn start(&self, state: &mut [u32]) {
+        start_all(&self.0, state)
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        push_all(&self.0, state, digit)
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        parts(&self.0, state).iter().all(|(rule, part)| rule.accepts(part))
+    }
+
+    fn describe(&self) -> String {
+        "all of".to_string()
+    }
+
+    fn children(&self) -> Vec<&dyn Rule> {
+        self.0.iter().map(|x| &**x).collect()
+    }
+}
+
+pub struct Or(pub Vec<Box<dyn Rule>>);
+
+impl Rule for Or {
+    fn width(&self) -> usize { self.0.iter().map(|x| x.width()).sum() }
+
+    fn start(&self, state: &mut [u32]) {
+        start_all(&self.0, state)
+    }
+
+    fn push(&self, state: &mut [u32], digit: u8) {
+        push_all(&self.0, state, digit)
+    }
+
+    fn accepts(&self, state: &[u32]) -> bool {
+        parts(&self.0, state).iter().any(|(rule, part)| rule.accepts(part))
+    }
+
+    fn describe(&self) -> String {
+        "any of".to_string()
+    }
+
+    fn children(&self) -> Vec<&dyn Rule> {
+        self.0.iter().map(|x| &**x).collect()
+    }
+}
+
+/// The puzzle's two rule sets.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Policy {
+    /// Digits never decrease and two adjacent ones are the same.
+    Double,
+    /// Digits never decrease and two adjacent ones are the same without being part of a
+    /// larger group.
+    ExactPair,
+}
+
+impl Policy {
+    pub fn rule(self) -> Box<dyn Rule> {
+        let run: Box<dyn Rule> = match self {
+            Policy::Double => Box::new(HasRunOfAtLeast(2)),
+            Policy::ExactPair => Box::new(HasRunOfExactly(2)),
+        };
+        Box::new(And(vec![Box::new(NonDecreasing), run]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs() {
+        assert!(HasRunOfExactly(2).check(112233));
+        assert!(!HasRunOfExactly(2).check(123444));
+        assert!(HasRunOfExactly(2).check(111122));
+        assert!(HasRunOfExactly(3).check(123444));
+        assert!(HasRunOfAtLeast(3).check(1222));
+        assert!(!HasRunOfAtLeast(3).check(1221));
+    }
+
+    #[test]
+    fn combinators() {
+        let sevens = || Box::new(DigitCount { digit: 7, min: 2, max: 2 });
+        let rule = Or(vec![Box::new(Not(Box::new(NonDecreasing))), sevens()]);
+        assert!(rule.check(21));
+        assert!(rule.check(1277));
+        assert!(!rule.check(1777));
+        assert!(!rule.check(1234));
+        let rule = And(vec![Box::new(NonDecreasing), sevens()]);
+        assert!(rule.check(1277));
+        assert!(!rule.check(7271));
+        assert!(Policy::Double.rule().check(111111));
+        assert!(!Policy::Double.rule().check(223450));
+        assert!(!Policy::ExactPair.rule().check(123444));
+
+        let any_sevens = DigitCount { digit: 7, min: 1, max: u32::MAX };
+        assert!(any_sevens.check(7_777_777_777));
+        assert!(!any_sevens.check(1234));
+    }
+}
@@ -0,0 +1,43 @@
+use common::{Answer, Solution};
+use intcode::{Machine, MachineStatus};
+
+/// Runs the diagnostic program for the given system ID. Every output but the last is a
+/// test result that should be zero; the last is the diagnostic code. `None` unless the
+/// program halts cleanly after printing something.
+fn diagnostic(mem: &[i64], system_id: i64) -> Option<i64> {
+    let mut machine = Machine::new(mem);
+    machine.easy_run(&[system_id]);
+    if machine.status != MachineStatus::Finished {
+        return None;
+    }
+    machine.outputs.last().copied()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const NAME: &'static str = "Sunny with a Chance of Asteroids";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        diagnostic(mem, 1).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        diagnostic(mem, 5).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+common::examples!(Day05 {
+    echo: part1("3,0,4,0,99") == 1,
+    equal_to_8: part2("3,9,8,9,10,9,4,9,99,-1,8") == 0,
+    less_than_8: part2("3,3,1107,-1,8,3,4,3,99") == 1,
+    below_8: part2("3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99") == 999,
+    bad_opcode: part1("3,0,4,0,98") == Answer::Unsolved,
+    no_output: part2("3,0,99") == Answer::Unsolved,
+});
@@ -0,0 +1,77 @@
+use common::{Answer, Solution};
+use std::collections::HashMap;
+
+fn get_total_orbits(orbits: &HashMap<String, String>, start: &str, end: &str) -> u64 {
+    let mut total = 0;
+    let mut current = start;
+    while current != end {
+        current = orbits.get(current).unwrap();
+        total += 1;
+    }
+    total
+}
+
+fn get_parents(orbits: &HashMap<String, String>, start: &str, end: &str) -> Vec<String> {
+    let mut path = Vec::new();
+    let mut current = start;
+    while current != end {
+        current = orbits.get(current).unwrap();
+        path.push(current.to_string());
+    }
+    path
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const NAME: &'static str = "Universal Orbit Map";
+    type Input = HashMap<String, String>;
+
+    fn parse(input: &str) -> common::Result<HashMap<String, String>> {
+        let mut orbits = HashMap::new();
+        for line in input.lines() {
+            let split: Vec<&str> = line.split(')').collect();
+            if split.len() != 2 {
+                return Err(format!("invalid orbit `{}`", line).into());
+            }
+            let body = split[0];
+            let sat = split[1];
+
+            orbits.insert(sat.to_string(), body.to_string());
+        }
+        Ok(orbits)
+    }
+
+    fn part1(orbits: &HashMap<String, String>) -> Answer {
+        let mut total_orbits = 0;
+        for body in orbits.keys() {
+            total_orbits += get_total_orbits(orbits, body, "COM");
+        }
+        total_orbits.into()
+    }
+
+    fn part2(orbits: &HashMap<String, String>) -> Answer {
+        // Find latest common parent
+        let you_path = get_parents(orbits, "YOU", "COM");
+        let san_path = get_parents(orbits, "SAN", "COM");
+        let mut common: &str = "COM";
+        let mut i = 1;
+        loop {
+            if you_path[you_path.len() - i] != san_path[san_path.len() - i] {
+                break;
+            } else {
+                common = &you_path[you_path.len() - i];
+                i += 1;
+            }
+        }
+
+        let transfers = get_total_orbits(orbits, "YOU", common) +  get_total_orbits(orbits, "SAN", common) - 2;
+        transfers.into()
+    }
+}
+
+common::examples!(Day06 {
+    total_orbits: part1("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L") == 42,
+    transfers: part2("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN") == 4,
+});
@@ -0,0 +1,86 @@
+use common::{Answer, Solution};
+use permutohedron::heap_recursive;
+use intcode::Machine;
+use intcode::MachineStatus::{Finished, Blocked};
+
+fn run_amplifiers(mem: &[i64], phases: Vec<i64>) -> i64 {
+    let mut val = 0;
+    for phase in phases {
+        let mut machine = Machine::new(mem);
+        let inputs = vec![phase, val];
+        let output = machine.easy_run(&inputs);
+        val = output[0];
+    }
+    val
+}
+
+fn run_amplifiers_loop(mem: &[i64], phases: Vec<i64>) -> i64 {
+    let mut val = 0;
+    let mut index = 0;
+    let mut machines: Vec<Machine> = phases.into_iter().map(|x| {
+        let mut machine = Machine::new(mem);
+        machine.add_input(x);
+        machine
+    }).collect();
+
+    loop {
+        machines[index].add_input(val);
+        machines[index].run();
+        val = machines[index].get_output().unwrap();
+
+        let status = machines[index].get_status();
+        match status {
+            Finished => {
+                if index == machines.len() - 1 {
+                    return val;
+                }
+            },
+            Blocked => {}
+            _ => { panic!("bad at {}: {:?}", index, status); }
+        }
+
+        index = (index + 1) % machines.len();
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const NAME: &'static str = "Amplification Circuit";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        let mut highest = 0;
+        let mut phases = vec![0, 1, 2, 3, 4];
+        heap_recursive(&mut phases, |permutation| {
+            let output = run_amplifiers(mem, permutation.to_vec());
+            if output > highest {
+                highest = output;
+            }
+        });
+        highest.into()
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        let mut highest = 0;
+        let mut phases = vec![5, 6, 7, 8, 9];
+        heap_recursive(&mut phases, |permutation| {
+            let output = run_amplifiers_loop(mem, permutation.to_vec());
+            if output > highest {
+                highest = output;
+            }
+        });
+        highest.into()
+    }
+}
+
+common::examples!(Day07 {
+    amplifiers_1: part1("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0") == 43210,
+    amplifiers_2: part1("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0") == 54321,
+    feedback_1: part2("3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5") == 139629729,
+});
@@ -0,0 +1,146 @@
+use common::{Answer, Solution};
+use common::grid::{Bounds, Grid};
+use common::image::{Palette, Picture};
+use common::ocr;
+
+pub struct Image {
+    w: usize,
+    h: usize,
+    data: Vec<u32>
+}
+
+impl Image {
+    fn num_layers(&self) -> usize {
+        self.data.len() / (self.w * self.h)
+    }
+
+    fn get(&self, layer: usize, x: usize, y: usize) -> u32 {
+        let index = (self.w * self.h * layer) + (y * self.w) + x;
+        self.data[index]
+    }
+
+    fn new(w: usize, h: usize, input: &str) -> common::Result<Image> {
+        let data = input.chars().map(|x| x.to_digit(10).ok_or(format!("invalid pixel `{}`", x)))
+            .collect::<Result<Vec<u32>, _>>()?;
+        if data.is_empty() {
+            return Err("image has no layers".into());
+        }
+        if data.len() % (w * h) != 0 {
+            return Err(format!("{} pixels is not a whole number of {}x{} layers", data.len(), w, h).into());
+        }
+        Ok(Image {
+            w,
+            h,
+            data
+        })
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds { min_x: 0, min_y: 0, max_x: self.w as i64 - 1, max_y: self.h as i64 - 1 }
+    }
+
+    fn count_digits(&self, layer: usize, kind: u32) -> u64 {
+        let mut count = 0;
+        for y in 0..self.h {
+            for x in 0..self.w {
+                if self.get(layer, x, y) == kind {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Stacks the layers into one, front first. A pixel transparent in every layer stays 2.
+    fn collapse(&self) -> Image {
+        let mut data = Vec::new();
+
+        let layers = self.num_layers();
+        for y in 0..self.h {
+            for x in 0..self.w {
+                let pixel = (0..layers).map(|i| self.get(i, x, y)).find(|&pixel| pixel < 2);
+                data.push(pixel.unwrap_or(2));
+            }
+        }
+
+        Image {
+            w: self.w,
+            h: self.h,
+            data
+        }
+    }
+}
+
+/// The puzzle for images `W` pixels wide and `H` tall.
+pub struct SpaceImage<const W: usize, const H: usize>;
+
+pub type Day08 = SpaceImage<25, 6>;
+
+impl<const W: usize, const H: usize> Solution for SpaceImage<W, H> {
+    const DAY: u32 = 8;
+    const NAME: &'static str = "Space Image Format";
+    type Input = Image;
+
+    fn parse(input: &str) -> common::Result<Image> {
+        Image::new(W, H, input.trim())
+    }
+
+    fn part1(image: &Image) -> Answer {
+        let mut best = 9999999;
+        let mut best_val = 0;
+        for i in 0..image.num_layers() {
+            let num0 = image.count_digits(i, 0);
+            if num0 < best {
+                best = num0;
+                let num1 = image.count_digits(i, 1);
+                let num2 = image.count_digits(i, 2);
+                best_val = num1 * num2;
+            }
+        }
+        best_val.into()
+    }
+
+    fn part2(image: &Image) -> Answer {
+        let image = image.collapse();
+        if let Ok(text) = ocr::recognize(image.bounds(), |(x, y)| image.get(0, x as usize, y as usize) == 1) {
+            return text.into();
+        }
+
+        // Not letters we know; leave it for a human to read.
+        let mut rows = Vec::new();
+        for y in 0..image.h {
+            let row: String = (0..image.w).map(|x| image.get(0, x, y).to_string()).collect();
+            rows.push(row);
+        }
+        rows.join("\n").into()
+    }
+
+    fn picture(image: &Image, part: u32) -> Option<Picture> {
+        if part != 2 {
+            return None;
+        }
+        let image = image.collapse();
+        let cells = image.data.iter().map(|x| *x as u8).collect();
+        Some(Picture::new(Grid::from_cells(image.w, image.h, cells), Palette::mono()))
+    }
+}
+
+common::examples!(SpaceImage<3, 2> {
+    fewest_zeros: part1("123456789012") == 1,
+    transparent_everywhere: part2("222222") == "222\n222",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::examples!(SpaceImage<2, 2> {
+        stacked_layers: part2("0222112222120000") == "01\n10",
+    });
+
+    #[test]
+    fn rejects_empty_image() {
+        assert_eq!(Day08::parse("\n").err().unwrap().to_string(), "image has no layers");
+        assert!(Day08::parse("0120").is_err());
+    }
+}
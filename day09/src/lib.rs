@@ -0,0 +1,39 @@
+use common::{Answer, Solution};
+use intcode::{Machine, MachineStatus};
+
+fn run_boost(mem: &[i64], mode: i64) -> Answer {
+    let mut machine = Machine::new(mem);
+    machine.add_input(mode);
+    machine.run();
+    match (machine.status, machine.get_output()) {
+        (MachineStatus::Finished, Some(output)) => output.into(),
+        _ => Answer::Unsolved,
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const NAME: &'static str = "Sensor Boost";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        run_boost(mem, 1)
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        run_boost(mem, 2)
+    }
+}
+
+common::examples!(Day09 {
+    large_output: part1("104,1125899906842624,99") == 1125899906842624_i64,
+    sixteen_digits: part1("1102,34915192,34915192,7,4,7,99,0") == 1219070632396864_i64,
+    bad_address: part1("4,-1,99") == Answer::Unsolved,
+    waits_for_input: part2("3,0,3,0,99") == Answer::Unsolved,
+});
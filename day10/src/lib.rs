@@ -0,0 +1,146 @@
+use common::{Answer, Solution};
+use common::geom::Point;
+use common::grid::Grid;
+
+fn angle_to(from: &Point, to: &Point) -> f64 {
+    let delta = *to - *from;
+    (-delta.y as f64).atan2(delta.x as f64)
+}
+
+fn dist2_to(from: &Point, to: &Point) -> f64 {
+    let delta = *to - *from;
+    ((delta.x * delta.x) + (delta.y * delta.y)) as f64
+}
+
+const FLOAT_DELTA: f64 = 0.00001;
+
+fn float_compare(a: f64, b: f64) -> bool {
+    (a - b).abs() < FLOAT_DELTA
+}
+
+fn angle_delta(x: f64, mut y: f64) -> f64 {
+    if y > x {
+        y -= 2_f64 * std::f64::consts::PI;
+    }
+    x - y
+}
+
+fn count_line_of_sight(asteroids: &[Point], start: &Point) -> usize {
+    let mut angles = Vec::new();
+    for a in asteroids {
+        if a == start {
+            continue;
+        }
+        let angle = angle_to(start, a);
+
+        let mut did_see = false;
+        for other in &angles {
+            if float_compare(angle, *other) {
+                did_see = true;
+                break;
+            }
+        }
+        if !did_see {
+            angles.push(angle);
+        }
+    }
+    angles.len()
+}
+
+fn get_destroyed(asteroids: &[Point], laser: &Point, count: usize) -> Point {
+    let mut asteroids = asteroids.to_vec();
+    // Inefficient but easier than sorting.
+    let mut current_angle = 1_f64.atan2(0 as f64);
+    current_angle += 2_f64 * FLOAT_DELTA;
+    let mut num = 0;
+    loop {
+        num += 1;
+        let mut next: i64 = -1;
+        let mut lowest_delta: f64 = 99999_f64;
+        let mut lowest_distance: f64 = 99999_f64;
+        for (i, other) in asteroids.iter().enumerate() {
+            let dist = dist2_to(laser, other);
+            let angle = angle_to(laser, other);
+            let delta = angle_delta(current_angle, angle);
+            if (delta < lowest_delta - FLOAT_DELTA) || (float_compare(delta, lowest_delta) && dist < lowest_distance) {
+                lowest_delta = delta;
+                lowest_distance = dist;
+                next = i as i64;
+            }
+        }
+        let removed = asteroids.remove(next as usize);
+        current_angle = angle_to(laser, &removed) - (2_f64 * FLOAT_DELTA);
+        if num == count {
+            return removed;
+        }
+    }
+}
+
+/// The asteroid that can see the most others, and how many it sees.
+fn best_station(asteroids: &[Point]) -> (Point, usize) {
+    let mut laser = Point::ORIGIN;
+    let mut best = 0;
+    for asteroid in asteroids {
+        let num = count_line_of_sight(asteroids, asteroid);
+        if num > best {
+            best = num;
+            laser = *asteroid;
+        }
+    }
+    (laser, best)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const NAME: &'static str = "Monitoring Station";
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> common::Result<Vec<Point>> {
+        let map = Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        Ok(map.iter().filter(|(_, asteroid)| **asteroid).map(|(pos, _)| Point::from(pos)).collect())
+    }
+
+    fn part1(asteroids: &Vec<Point>) -> Answer {
+        best_station(asteroids).1.into()
+    }
+
+    fn part2(asteroids: &Vec<Point>) -> Answer {
+        let (laser, _) = best_station(asteroids);
+        let destroyed = get_destroyed(asteroids, &laser, 200);
+        ((destroyed.x * 100) + destroyed.y).into()
+    }
+}
+
+#[cfg(test)]
+const LARGE_EXAMPLE: &str = ".#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##";
+
+common::examples!(Day10 {
+    small: part1(".#..#\n.....\n#####\n....#\n...##") == 8,
+    large: part1(LARGE_EXAMPLE) == 210,
+    vaporized_200th: part2(LARGE_EXAMPLE) == 802,
+});
@@ -0,0 +1,104 @@
+use common::{Answer, Solution};
+use common::geom::{Code, Direction, Point, Turn};
+use common::grid::SparseGrid;
+use common::image::{Palette, Picture};
+use common::ocr;
+use intcode::{Machine, MachineStatus};
+
+/// The robot's second output: which way to turn.
+struct PaintTurn;
+
+impl Code<Turn> for PaintTurn {
+    fn encode(turn: Turn) -> Option<i64> {
+        match turn {
+            Turn::Left => Some(0),
+            Turn::Right => Some(1),
+            Turn::Around | Turn::Straight => None,
+        }
+    }
+
+    fn decode(code: i64) -> Option<Turn> {
+        match code {
+            0 => Some(Turn::Left),
+            1 => Some(Turn::Right),
+            _ => None,
+        }
+    }
+}
+
+fn run_robot(mut map: SparseGrid<i64>, mut machine: Machine) -> SparseGrid<i64> {
+    let mut pos = Point::ORIGIN;
+    let mut dir = Direction::Up;
+
+    loop {
+        machine.run();
+        match machine.status {
+            MachineStatus::Finished => { break; },
+            MachineStatus::Runnable => { continue; },
+            MachineStatus::Blocked => {
+                // Get input:
+                let space = map.get(pos.into());
+                let color = *space.unwrap_or(&0);
+                machine.add_input(color);
+                machine.run();
+
+                // See if there's output
+                let out1 = machine.get_output();
+                let out2 = machine.get_output();
+                if let Some(new_color) = out1 {
+                    let turn = PaintTurn::decode(out2.unwrap()).expect("invalid rotation");
+                    map.insert(pos.into(), new_color);
+
+                    dir = dir.turn(turn);
+                    pos = pos.step(dir);
+                }
+            },
+            _ => { panic!("invalid machine state"); }
+        };
+    }
+
+    map
+}
+
+/// Runs the robot from a single panel of the given colour.
+fn paint(mem: &[i64], start_color: i64) -> SparseGrid<i64> {
+    let mut map = SparseGrid::new();
+    if start_color != 0 {
+        map.insert((0, 0), start_color);
+    }
+    run_robot(map, Machine::new(mem))
+}
+
+fn render_map(map: &SparseGrid<i64>) -> String {
+    map.render(|color| if *color.unwrap_or(&0) != 0 { '\u{2588}' } else { ' ' })
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const NAME: &'static str = "Space Police";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        paint(mem, 0).len().into()
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        let map = paint(mem, 1);
+        match map.bounds().map(|bounds| ocr::recognize(bounds, |pos| map.get(pos) == Some(&1))) {
+            Some(Ok(text)) => text.into(),
+            // Not letters we know; leave it for a human to read.
+            _ => render_map(&map).into(),
+        }
+    }
+
+    fn picture(mem: &Vec<i64>, part: u32) -> Option<Picture> {
+        let map = paint(mem, if part == 1 { 0 } else { 1 });
+        Some(Picture::from_sparse(&map, |color| *color.unwrap_or(&0) as u8, Palette::mono()))
+    }
+}
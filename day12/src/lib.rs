@@ -1,14 +1,6 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use common::{Answer, Solution};
 extern crate num_integer;
 
-fn read_lines(filename: &str) -> impl Iterator<Item=String> {
-    let file = File::open(filename).unwrap();
-    let reader = BufReader::new(file);
-
-    reader.lines().map(|line| line.unwrap())
-}
-
 #[derive(Clone, Eq)]
 struct Body {
     x: i64,
@@ -72,31 +64,35 @@ impl PartialEq for Body {
 }
 
 #[derive(Clone, Eq)]
-struct World {
+pub struct World {
     bodies: Vec<Body>,
 }
 
 impl World {
-    fn new(input: impl Iterator<Item=String>) -> World {
-        let bodies: Vec<Body> =
-            input
-                .map(|x| x
-                    .split(",")
-                    .map(|y| y.parse::<i64>().unwrap())
-                    .collect()
-                )
-                .map(|coords: Vec<i64>| Body {
-                    x: *coords.get(0).unwrap(),
-                    y: *coords.get(1).unwrap(),
-                    z: *coords.get(2).unwrap(),
-                    vx: 0,
-                    vy: 0,
-                    vz: 0,
-                }).collect();
-
-        World {
-            bodies,
+    /// Accepts either the puzzle's `<x=-1, y=0, z=2>` lines or bare `-1,0,2` triples.
+    fn new(input: &str) -> common::Result<World> {
+        let mut bodies = Vec::new();
+        for line in input.lines() {
+            let coords = line
+                .split(',')
+                .map(|x| x.trim_matches(|c: char| " <>xyz=".contains(c)).parse::<i64>())
+                .collect::<Result<Vec<i64>, _>>()?;
+            if coords.len() != 3 {
+                return Err(format!("expected 3 coordinates, found `{}`", line).into());
+            }
+            bodies.push(Body {
+                x: coords[0],
+                y: coords[1],
+                z: coords[2],
+                vx: 0,
+                vy: 0,
+                vz: 0,
+            });
         }
+
+        Ok(World {
+            bodies,
+        })
     }
 
     fn step(&mut self) {
@@ -150,21 +146,38 @@ fn step_equality<F>(source: &World, compare: F) -> u64
     period
 }
 
-fn main() {
-    let source = World::new(read_lines("input.in"));
+pub struct Day12;
 
-    let mut world = source.clone();
-    let steps = 1000;
-    for _ in 0..steps {
-        world.step();
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const NAME: &'static str = "The N-Body Problem";
+    type Input = World;
+
+    fn parse(input: &str) -> common::Result<World> {
+        World::new(input)
     }
-    println!("Part 1 total energy: {}", world.energy());
 
-    // Part 2. ugh
-    let x_period = step_equality(&source, |a, b| a.eq_x(b));
-    let y_period = step_equality(&source, |a, b| a.eq_y(b));
-    let z_period = step_equality(&source, |a, b| a.eq_z(b));
-    let mut lcm: u64 = num_integer::lcm(x_period, y_period);
-    lcm = num_integer::lcm(lcm, z_period);
-    println!("Part 2: {}", lcm);
+    fn part1(world: &World) -> Answer {
+        let mut world = world.clone();
+        let steps = 1000;
+        for _ in 0..steps {
+            world.step();
+        }
+        world.energy().into()
+    }
+
+    fn part2(source: &World) -> Answer {
+        // Part 2. ugh
+        let x_period = step_equality(source, |a, b| a.eq_x(b));
+        let y_period = step_equality(source, |a, b| a.eq_y(b));
+        let z_period = step_equality(source, |a, b| a.eq_z(b));
+        let mut lcm: u64 = num_integer::lcm(x_period, y_period);
+        lcm = num_integer::lcm(lcm, z_period);
+        lcm.into()
+    }
 }
+
+common::examples!(Day12 {
+    period_1: part2("<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>") == 2772,
+    period_2: part2("<x=-8, y=-10, z=0>\n<x=5, y=5, z=10>\n<x=2, y=-7, z=3>\n<x=9, y=-8, z=-3>") == 4686774924_u64,
+});
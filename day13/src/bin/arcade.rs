@@ -0,0 +1,35 @@
+use std::env;
+
+use common::{default_input, fail};
+use day13::World;
+use intcode::Machine;
+use intcode::session::Session;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let flag = |name: &str| args.iter().position(|x| x == name).map(|i| {
+        args.get(i + 1).unwrap_or_else(|| fail(&format!("{} needs a value", name))).clone()
+    });
+
+    let input = flag("--input").unwrap_or_else(|| default_input(13));
+    let mem = intcode::loader::load(&input).unwrap_or_else(|e| fail(&format!("{}: {}", input, e)));
+
+    let mut machine = Machine::new(&mem);
+    if flag("--record").is_some() {
+        machine.record_inputs();
+    }
+    let mut world = World::new(machine);
+    match flag("--replay") {
+        Some(path) => {
+            let session = Session::load(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+            world.replay(&session).unwrap_or_else(|e| fail(&e.to_string()));
+        }
+        None => world.play().unwrap_or_else(|status| fail(&format!("machine stopped: {:?}", status))),
+    }
+    world.print();
+
+    if let Some(path) = flag("--record") {
+        let session = world.machine.session().expect("inputs are being recorded");
+        session.save(&path).unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+    }
+}
@@ -0,0 +1,201 @@
+use common::{Answer, Solution};
+use intcode::{Machine, MachineStatus};
+use intcode::session::{Divergence, Session};
+use common::grid::SparseGrid;
+use common::image::{Palette, Picture};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
+pub struct World {
+    pub machine:  Machine,
+    tiles: SparseGrid<i64>,
+    pub score: i64,
+
+    paddle_x: i64,
+    ball_x: i64,
+}
+
+impl World {
+    pub fn new(machine: Machine) -> World {
+        World {
+            machine,
+            tiles: SparseGrid::new(),
+            score: 0,
+            paddle_x: 0,
+            ball_x: 0,
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}", self.tiles.render(|tile| match tile.unwrap_or(&0) {
+            0 => ' ',
+            1 => '#',
+            2 => 'x',
+            3 => '-',
+            4 => 'o',
+            _ => '?',
+        }));
+        println!("Score: {}", self.score);
+    }
+
+    /// The screen: empty, wall, block, paddle and ball tiles.
+    pub fn picture(&self) -> Picture {
+        let palette = Palette(vec![[0, 0, 0], [128, 128, 128], [224, 112, 32], [255, 255, 255], [255, 224, 0]]);
+        // Tile IDs that don't fit get the palette's colour for a missing entry.
+        Picture::from_sparse(&self.tiles, |tile| u8::try_from(*tile.unwrap_or(&0)).unwrap_or(u8::MAX), palette)
+    }
+
+    pub fn process(&mut self) {
+        self.machine.run();
+        let output = &self.machine.outputs;
+
+        for chunk in output.chunks(3) {
+            let x = chunk[0];
+            let y = chunk[1];
+            let tile = chunk[2];
+            self.tiles.insert((x, y), tile);
+        }
+    }
+
+    fn update(&mut self) {
+        for chunk in self.machine.outputs.chunks(3) {
+            let x = chunk[0];
+            let y = chunk[1];
+            let tile = chunk[2];
+            if x == -1 && y == 0 {
+                self.score = tile;
+            } else {
+                self.tiles.insert((x, y), tile);
+
+                if tile == 3 {
+                    self.paddle_x = x;
+                }
+                if tile == 4 {
+                    self.ball_x = x;
+                }
+            }
+        }
+    }
+
+    /// Plays until every block is gone or the game ends, moving the paddle towards the ball.
+    /// Fails with the machine's status if the program faults.
+    pub fn play(&mut self) -> Result<(), MachineStatus> {
+        self.machine.mem[0] = 2;
+
+        loop {
+            self.machine.outputs.clear();
+            self.machine.run();
+            self.update();
+
+            match self.machine.status {
+                MachineStatus::Finished => return Ok(()),
+                MachineStatus::Blocked => {}
+                status => return Err(status),
+            }
+            if self.count_blocks() == 0 {
+                return Ok(());
+            }
+
+            self.machine.add_input(match self.ball_x.cmp(&self.paddle_x) {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            });
+        }
+    }
+
+    pub fn replay(&mut self, session: &Session) -> Result<(), Divergence> {
+        self.machine.mem[0] = 2;
+        session.replay(&mut self.machine)?;
+        self.update();
+        Ok(())
+    }
+
+    pub fn count_blocks(&self) -> usize {
+        self.tiles.values().filter(|x| **x == 2).count()
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const NAME: &'static str = "Care Package";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        let mut world = World::new(Machine::new(mem));
+        world.process();
+        world.count_blocks().into()
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        let mut world = World::new(Machine::new(mem));
+        match world.play() {
+            Ok(()) => world.score.into(),
+            Err(_) => Answer::Unsolved,
+        }
+    }
+
+    fn picture(mem: &Vec<i64>, part: u32) -> Option<Picture> {
+        let mut world = World::new(Machine::new(mem));
+        if part == 1 {
+            world.process();
+        } else {
+            world.play().ok()?;
+        }
+        Some(world.picture())
+    }
+}
+
+common::examples!(Day13 {
+    // With the quarters set, the first instruction multiplies into address -1.
+    faulting_game: part2("1,5,5,-1,99,0") == Answer::Unsolved,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads two joystick moves, draws one block and scores their sum.
+    const GAME: [i64; 34] = [
+        1, 30, 30, 30, 3, 31, 3, 32, 1, 31, 32, 33,
+        104, 4, 104, 1, 104, 2, 104, -1, 104, 0, 4, 33, 99,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+
+    fn record(inputs: &[i64]) -> Session {
+        let mut machine = Machine::new(&GAME);
+        machine.record_inputs();
+        machine.mem[0] = 2;
+        for &input in inputs {
+            machine.add_input(input);
+        }
+        machine.run();
+        assert_eq!(machine.status, MachineStatus::Finished);
+        machine.session().unwrap().clone()
+    }
+
+    #[test]
+    fn replays_recorded_session() {
+        let session = record(&[1, 1]);
+        let mut world = World::new(Machine::new(&GAME));
+        assert_eq!(world.replay(&session), Ok(()));
+        assert_eq!(world.machine.status, MachineStatus::Finished);
+        assert_eq!(world.score, 2);
+        assert_eq!(world.count_blocks(), 1);
+    }
+
+    #[test]
+    fn replay_reports_divergence() {
+        let mut session = record(&[1, 1]);
+        session.inputs[1].step += 1;
+        let mut world = World::new(Machine::new(&GAME));
+        let expected = session.inputs[1].step;
+        assert_eq!(world.replay(&session), Err(Divergence::Step { index: 1, expected, actual: expected - 1 }));
+    }
+}
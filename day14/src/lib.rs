@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use common::{Answer, Solution};
+
+struct Chemical {
+    quantity: i64,
+    name: String,
+}
+
+impl Chemical {
+    fn parse(str: &str) -> common::Result<Chemical> {
+        let parts: Vec<&str> = str.split(' ').collect();
+        if parts.len() != 2 {
+            return Err(format!("expected `<quantity> <name>`, found `{}`", str).into());
+        }
+        let quantity: i64 = parts[0].parse()?;
+        Ok(Chemical {
+            quantity,
+            name: parts[1].to_string(),
+        })
+    }
+}
+
+pub struct Reaction {
+    input: Vec<Chemical>,
+    output: Chemical
+}
+
+fn parse_reaction(s: &str) -> common::Result<Reaction> {
+    let mut chems = s.split(" => ").flat_map(|x| x.split(", ")).map(Chemical::parse).collect::<common::Result<Vec<_>>>()?;
+    let to = chems.pop().unwrap();
+    Ok(Reaction {
+        input: chems,
+        output: to,
+    })
+}
+
+fn process(reactions: &HashMap<String, Reaction>, haves: &mut HashMap<String, i64>) {
+    while let Some((product, amount)) = haves.iter().find(|(product, amount)| **amount < 0 && *product != "ORE") {
+        let needed = -*amount;
+        let product = product.to_string();
+        // println!("{} {}:", needed, product);
+
+        let reaction = reactions.get(&product).unwrap();
+        let multiply = ((needed as f32) / (reaction.output.quantity as f32)).ceil() as i64;
+
+        for input in &reaction.input {
+            let stock = *haves.get(&input.name).unwrap_or(&0);
+            // println!(" with {} {} (have {})", input.quantity * multiply, &input.name, stock);
+            haves.insert(input.name.to_string(), stock - (input.quantity * multiply));
+        }
+
+        let produced = multiply * reaction.output.quantity;
+        haves.insert(product, produced - needed);
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const NAME: &'static str = "Space Stoichiometry";
+    type Input = HashMap<String, Reaction>;
+
+    fn parse(input: &str) -> common::Result<HashMap<String, Reaction>> {
+        let mut reactions = HashMap::new();
+        for line in input.lines() {
+            let reaction = parse_reaction(line)?;
+            reactions.insert(reaction.output.name.to_string(), reaction);
+        }
+        Ok(reactions)
+    }
+
+    fn part1(reactions: &HashMap<String, Reaction>) -> Answer {
+        let mut haves: HashMap<String, i64> = HashMap::new();
+        haves.insert("ORE".to_string(), 0);
+        haves.insert("FUEL".to_string(), -1);
+        process(reactions, &mut haves);
+        (-*haves.get("ORE").unwrap()).into()
+    }
+
+    fn part2(reactions: &HashMap<String, Reaction>) -> Answer {
+        let mut fuel: i64 = 0;
+        let mut haves: HashMap<String, i64> = HashMap::new();
+        haves.insert("ORE".to_string(), 0);
+        loop {
+            haves.insert("FUEL".to_string(), -1);
+            process(reactions, &mut haves);
+
+            let ore = -*haves.get("ORE").unwrap();
+            if ore < 1_000_000_000_000 {
+                fuel += 1;
+            } else {
+                break;
+            }
+        }
+        fuel.into()
+    }
+}
+
+common::examples!(Day14 {
+    simple: part1("10 ORE => 10 A\n1 ORE => 1 B\n7 A, 1 B => 1 C\n7 A, 1 C => 1 D\n7 A, 1 D => 1 E\n7 A, 1 E => 1 FUEL") == 31,
+    two_ores: part1("9 ORE => 2 A\n8 ORE => 3 B\n7 ORE => 5 C\n3 A, 4 B => 1 AB\n5 B, 7 C => 1 BC\n4 C, 1 A => 1 CA\n2 AB, 3 BC, 4 CA => 1 FUEL") == 165,
+});
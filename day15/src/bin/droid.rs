@@ -0,0 +1,39 @@
+use std::env;
+
+use common::{default_input, fail};
+use day15::{Robot, World};
+use intcode::Machine;
+use intcode::session::Session;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let flag = |name: &str| args.iter().position(|x| x == name).map(|i| {
+        args.get(i + 1).unwrap_or_else(|| fail(&format!("{} needs a value", name))).clone()
+    });
+
+    let input = flag("--input").unwrap_or_else(|| default_input(15));
+    let mem = intcode::loader::load(&input).unwrap_or_else(|e| fail(&format!("{}: {}", input, e)));
+
+    // Record the droid walking straight to the oxygen system.
+    if let Some(file) = flag("--record") {
+        let mut world = World::new(&mem);
+        world.explore();
+        let oxygen_pos = world.get_oxygen_pos();
+        println!("pos: {}, {}", oxygen_pos.x, oxygen_pos.y);
+
+        let mut robot = Robot::new(Machine::new(&mem));
+        robot.machine.record_inputs();
+        for dir in &world.get_path(&oxygen_pos) {
+            robot.step(dir);
+        }
+        let session = robot.machine.session().expect("inputs are being recorded");
+        session.save(&file).unwrap_or_else(|e| fail(&format!("{}: {}", file, e)));
+    }
+
+    if let Some(file) = flag("--replay") {
+        let session = Session::load(&file).unwrap_or_else(|e| fail(&format!("{}: {}", file, e)));
+        let mut machine = Machine::new(&mem);
+        session.replay(&mut machine).unwrap_or_else(|e| fail(&e.to_string()));
+        println!("replayed {} moves, last status: {:?}", session.inputs.len(), machine.outputs.last());
+    }
+}
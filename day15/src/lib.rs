@@ -0,0 +1,175 @@
+use common::{Answer, Solution};
+use common::geom::{Code, Direction, Point};
+use common::grid::SparseGrid;
+use common::image::{Palette, Picture};
+use common::search::{self, Paths};
+use intcode::Machine;
+use std::collections::HashMap;
+
+/// The droid's movement commands.
+pub struct Movement;
+
+impl Code<Direction> for Movement {
+    fn encode(direction: Direction) -> Option<i64> {
+        match direction {
+            Direction::Up => Some(1),
+            Direction::Down => Some(2),
+            Direction::Left => Some(3),
+            Direction::Right => Some(4),
+        }
+    }
+
+    fn decode(code: i64) -> Option<Direction> {
+        match code {
+            1 => Some(Direction::Up),
+            2 => Some(Direction::Down),
+            3 => Some(Direction::Left),
+            4 => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TileKind {
+    Empty,
+    Wall,
+    Oxygen,
+    Unknown,
+}
+
+#[derive(Clone)]
+pub struct Robot {
+    pub machine: Machine,
+}
+
+impl Robot {
+    pub fn new(machine: Machine) -> Robot {
+        Robot { machine }
+    }
+
+    pub fn step(&mut self, dir: &Direction) -> TileKind {
+        self.machine.add_input(Movement::encode(*dir).unwrap());
+        self.machine.run();
+        let status = self.machine.get_output();
+        match status {
+            Some(0) => TileKind::Wall,
+            Some(1) => TileKind::Empty,
+            Some(2) => TileKind::Oxygen,
+            _ => TileKind::Unknown,
+        }
+    }
+}
+
+pub struct World {
+    mem: Vec<i64>,
+    tiles: HashMap<Point, TileKind>,
+    paths: Option<Paths<Point>>,
+}
+
+impl World {
+    pub fn new(mem: &[i64]) -> World {
+        World {
+            mem: mem.to_vec(),
+            tiles: HashMap::new(),
+            paths: None,
+        }
+    }
+
+    /// Moves from the start to `target`, along the paths found by `explore`.
+    pub fn get_path(&self, target: &Point) -> Vec<Direction> {
+        let path = self.paths.as_ref().and_then(|paths| paths.path_to(target)).unwrap_or_default();
+        path.windows(2).map(|step| step[0].direction_to(step[1]).unwrap()).collect()
+    }
+
+    /// Maps the whole area. Each open tile keeps a copy of the droid that reached it, so
+    /// probing its neighbours doesn't replay the walk from the start.
+    pub fn explore(&mut self) {
+        let tiles = &mut self.tiles;
+        let mut robots = HashMap::new();
+        tiles.insert(Point::ORIGIN, TileKind::Empty);
+        robots.insert(Point::ORIGIN, Robot::new(Machine::new(&self.mem)));
+
+        self.paths = Some(search::bfs(Point::ORIGIN, |pos: &Point| {
+            let robot: Robot = robots.remove(pos).unwrap();
+            let mut open = Vec::new();
+            for dir in &Direction::ALL {
+                let neighbor = pos.step(*dir);
+                if tiles.contains_key(&neighbor) {
+                    continue;
+                }
+
+                let mut moved = robot.clone();
+                let tile = moved.step(dir);
+                tiles.insert(neighbor, tile);
+                if tile != TileKind::Wall {
+                    robots.insert(neighbor, moved);
+                    open.push(neighbor);
+                }
+            }
+            open
+        }));
+    }
+
+    /// The explored map, with the route to the oxygen system drawn in if `show_path` is set.
+    pub fn picture(&self, show_path: bool) -> Picture {
+        // Unknown, empty, wall, oxygen, path.
+        let palette = Palette(vec![[0, 0, 0], [224, 224, 224], [64, 64, 64], [32, 96, 255], [224, 32, 32]]);
+        let mut map: SparseGrid<u8> = self.tiles.iter().map(|(pos, tile)| ((*pos).into(), match tile {
+            TileKind::Empty => 1,
+            TileKind::Wall => 2,
+            TileKind::Oxygen => 3,
+            TileKind::Unknown => 0,
+        })).collect();
+        if show_path {
+            let mut pos = Point::ORIGIN;
+            for dir in self.get_path(&self.get_oxygen_pos()).iter().rev().skip(1).rev() {
+                pos = pos.step(*dir);
+                map.insert(pos.into(), 4);
+            }
+        }
+        Picture::from_sparse(&map, |tile| *tile.unwrap_or(&0), palette)
+    }
+
+    pub fn get_oxygen_pos(&self) -> Point {
+        let (pos, _) = self.tiles.iter().find(|(_, v)| **v == TileKind::Oxygen).unwrap();
+        *pos
+    }
+
+    pub fn time_to_spread(&self) -> usize {
+        let time = search::time_to_fill(self.get_oxygen_pos(), |pos: &Point| {
+            pos.neighbors4().filter(|neighbor| self.tiles.get(neighbor) == Some(&TileKind::Empty)).collect::<Vec<_>>()
+        });
+        time as usize
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const NAME: &'static str = "Oxygen System";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> common::Result<Vec<i64>> {
+        Ok(intcode::loader::parse(input)?)
+    }
+
+    fn part1(mem: &Vec<i64>) -> Answer {
+        let mut world = World::new(mem);
+        world.explore();
+        world.get_path(&world.get_oxygen_pos()).len().into()
+    }
+
+    fn part2(mem: &Vec<i64>) -> Answer {
+        let mut world = World::new(mem);
+        world.explore();
+        world.time_to_spread().into()
+    }
+
+    fn picture(mem: &Vec<i64>, part: u32) -> Option<Picture> {
+        let mut world = World::new(mem);
+        world.explore();
+        Some(world.picture(part == 1))
+    }
+}
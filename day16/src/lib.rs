@@ -0,0 +1,55 @@
+use common::{Answer, Solution};
+
+fn fft(digits: &[i32]) -> Vec<i32> {
+    let pattern: [i32; 4] = [0, 1, 0, -1];
+    let mut out = Vec::new();
+    for out_pos in 0..digits.len() {
+        let mut acc: i32 = 0;
+        for (in_pos, digit) in digits.iter().enumerate() {
+            let p = pattern[((in_pos + 1) / (out_pos + 1)) % 4];
+
+            acc += p * (*digit);
+        }
+        out.push(acc.abs() % 10);
+    }
+    out
+}
+
+/// The first eight digits, or all of them if there are fewer.
+fn mini_print(digits: &[i32]) -> String {
+    digits.iter().take(8).map(|x| x.to_string()).collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const NAME: &'static str = "Flawed Frequency Transmission";
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> common::Result<Vec<i32>> {
+        input.trim().chars().map(|x| match x.to_digit(10) {
+            Some(digit) => Ok(digit as i32),
+            None => Err(format!("invalid digit `{}`", x).into()),
+        }).collect()
+    }
+
+    fn part1(digits: &Vec<i32>) -> Answer {
+        let mut part1 = digits.to_vec();
+        for _ in 0..100 {
+            part1 = fft(&part1);
+        }
+        mini_print(&part1).into()
+    }
+
+    fn part2(_digits: &Vec<i32>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+common::examples!(Day16 {
+    example_1: part1("80871224585914546619083218645595") == "24176176",
+    example_2: part1("19617804207202209144916044189917") == "73745418",
+    example_3: part1("69317163492948606335995924319873") == "52432133",
+    short_signal: part1("1234567") == "4384567",
+});
@@ -0,0 +1,86 @@
+use std::io::{self, BufRead, Write};
+
+use crate::{Machine, MachineStatus};
+
+/// Output drained from a machine running an ASCII program.
+#[derive(Debug, Default, PartialEq)]
+pub struct AsciiOutput {
+    pub text: String,
+    /// Values outside 0 to 127, negative ones included, which such programs use to report their
+    /// answer.
+    pub values: Vec<i64>,
+}
+
+impl Machine {
+    /// Queues `line` as character codes, followed by a newline.
+    pub fn add_ascii_input(&mut self, line: &str) {
+        self.inputs.extend(line.bytes().map(|b| b as i64));
+        self.add_input('\n' as i64);
+    }
+
+    /// Takes every output not yet read, decoding the ones from 0 to 127 as text. Anything else,
+    /// above 127 or negative, goes to `values` in the order it was output.
+    pub fn get_ascii_output(&mut self) -> AsciiOutput {
+        let mut output = AsciiOutput::default();
+        while let Some(val) = self.get_output() {
+            if (0..=127).contains(&val) {
+                output.text.push(val as u8 as char);
+            } else {
+                output.values.push(val);
+            }
+        }
+        output
+    }
+}
+
+/// Runs `machine` until it halts, echoing its text to `output` and feeding it lines typed on `input`.
+pub fn interact(machine: &mut Machine, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut lines = input.lines();
+    loop {
+        machine.run();
+
+        let drained = machine.get_ascii_output();
+        write!(output, "{}", drained.text)?;
+        for val in drained.values {
+            writeln!(output, "{}", val)?;
+        }
+        output.flush()?;
+
+        match machine.status {
+            MachineStatus::Blocked => {
+                match lines.next() {
+                    Some(line) => machine.add_ascii_input(line?.trim_end_matches('\r')),
+                    None => { return Ok(()); }
+                }
+            }
+            MachineStatus::Finished => { return Ok(()); }
+            status => {
+                writeln!(output, "machine stopped: {:?}", status)?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_a_newline() {
+        let mut machine = Machine::new(&[99]);
+        machine.add_ascii_input("NOT A J");
+        machine.add_ascii_input("");
+        assert_eq!(machine.inputs, vec![78, 79, 84, 32, 65, 32, 74, 10, 10]);
+    }
+
+    #[test]
+    fn splits_text_and_values() {
+        let mut machine = Machine::new(&[99]);
+        machine.outputs = vec![72, 105, 10, 128, 0, 127, -1, 19_349_530, 33];
+        let output = machine.get_ascii_output();
+        assert_eq!(output.text, "Hi\n\0\x7f!");
+        assert_eq!(output.values, vec![128, -1, 19_349_530]);
+        assert_eq!(machine.get_ascii_output(), AsciiOutput::default());
+    }
+}
@@ -0,0 +1,23 @@
+use std::env;
+use std::io;
+use std::process;
+
+use intcode::ascii::interact;
+use intcode::loader::load;
+use intcode::Machine;
+
+fn main() {
+    let program = env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("usage: ascii <program>");
+        process::exit(2);
+    });
+
+    let mem = load(&program).unwrap_or_else(|e| {
+        eprintln!("{}: {}", program, e);
+        process::exit(1);
+    });
+
+    let mut machine = Machine::new(&mem);
+    let stdin = io::stdin();
+    interact(&mut machine, stdin.lock(), io::stdout()).unwrap();
+}
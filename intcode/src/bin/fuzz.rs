@@ -0,0 +1,65 @@
+use std::cmp::min;
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use intcode::fuzz::{format_input, Config, Fuzzer};
+use intcode::loader::load;
+
+fn usage() -> ! {
+    eprintln!("usage: fuzz <program> [--corpus <dir>] [--runs <n>] [--seed <n>] [--max-steps <n>] [--max-len <n>]");
+    process::exit(2);
+}
+
+fn number<T: std::str::FromStr>(arg: Option<String>) -> T {
+    arg.and_then(|x| x.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn main() {
+    let mut program = None;
+    let mut corpus = None;
+    let mut runs: u64 = 100_000;
+    let mut config = Config::default();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--corpus" => corpus = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            "--runs" => runs = number(args.next()),
+            "--seed" => config.seed = number(args.next()),
+            "--max-steps" => config.max_steps = number(args.next()),
+            "--max-len" => config.max_len = number(args.next()),
+            _ if program.is_none() && !arg.starts_with("--") => program = Some(arg),
+            _ => usage(),
+        }
+    }
+
+    let program = program.unwrap_or_else(|| usage());
+    let mem = load(&program).unwrap_or_else(|e| {
+        eprintln!("{}: {}", program, e);
+        process::exit(1);
+    });
+
+    let mut fuzzer = Fuzzer::new(&mem, config);
+    if let Some(dir) = corpus {
+        fuzzer = fuzzer.with_dir(&dir).unwrap();
+    }
+
+    let mut done = 0;
+    while done < runs {
+        let batch = min(10_000, runs - done);
+        fuzzer.run(batch).unwrap();
+        done += batch;
+        println!("execs: {}, corpus: {}, edges: {}, crashes: {}",
+                 fuzzer.executions, fuzzer.corpus().len(), fuzzer.edges(), fuzzer.crashes().len());
+    }
+
+    for crash in fuzzer.crashes() {
+        print!("{} at {} ({:?}): {}", crash.kind, crash.pos, crash.status, format_input(&crash.input));
+    }
+
+    println!("rarest outputs:");
+    for (val, count, input) in fuzzer.rare_outputs().into_iter().take(10) {
+        print!("{} x{}: {}", val, count, format_input(input));
+    }
+}
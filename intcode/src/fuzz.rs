@@ -0,0 +1,381 @@
+use std::cmp::min;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Machine, MachineStatus};
+
+const INTERESTING: [i64; 9] = [0, 1, -1, 2, 10, 100, 1000, i64::MAX, i64::MIN];
+
+pub struct Config {
+    /// Executions that run this many instructions are reported as hangs.
+    pub max_steps: u64,
+    /// Longest input queue the mutator will produce.
+    pub max_len: usize,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            max_steps: 100_000,
+            max_len: 64,
+            seed: 1,
+        }
+    }
+}
+
+/// The result of running a program once on a fixed input queue.
+pub struct Execution {
+    pub status: MachineStatus,
+    pub pos: usize,
+    pub consumed: usize,
+    pub outputs: Vec<i64>,
+    pub edges: HashSet<(usize, usize)>,
+}
+
+impl Execution {
+    fn crash_key(&self) -> Option<(&'static str, usize)> {
+        crash_kind(self.status).map(|kind| (kind, self.pos))
+    }
+}
+
+pub fn execute(program: &[i64], input: &[i64], max_steps: u64) -> Execution {
+    let mut machine = Machine::new(program);
+    machine.step_limit = Some(max_steps);
+    machine.enable_coverage();
+    machine.add_inputs(input);
+    machine.run();
+
+    Execution {
+        status: machine.status,
+        pos: machine.pos,
+        consumed: machine.input_pos,
+        edges: machine.coverage.take().unwrap_or_default(),
+        outputs: machine.outputs,
+    }
+}
+
+fn crash_kind(status: MachineStatus) -> Option<&'static str> {
+    match status {
+        MachineStatus::BadOpcode(_) => Some("bad-opcode"),
+        MachineStatus::BadMode(_) => Some("bad-mode"),
+        MachineStatus::BadAddress(_) => Some("bad-address"),
+        MachineStatus::StepLimit => Some("hang"),
+        _ => None,
+    }
+}
+
+pub struct Crash {
+    pub kind: &'static str,
+    pub pos: usize,
+    pub status: MachineStatus,
+    /// The minimised input that triggers this crash.
+    pub input: Vec<i64>,
+}
+
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        Rng((seed ^ 0x9e37_79b9_7f4a_7c15).max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        // xorshift64*
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+pub struct Fuzzer {
+    program: Vec<i64>,
+    config: Config,
+    rng: Rng,
+    dictionary: Vec<i64>,
+    dir: Option<PathBuf>,
+
+    corpus: Vec<Vec<i64>>,
+    edges: HashSet<(usize, usize)>,
+    outputs: HashMap<i64, (u64, Vec<i64>)>,
+    crashes: Vec<Crash>,
+    pub executions: u64,
+}
+
+impl Fuzzer {
+    pub fn new(program: &[i64], config: Config) -> Fuzzer {
+        // Constants from the program make good guesses for values it compares input against.
+        let mut dictionary: Vec<i64> = program.iter().chain(INTERESTING.iter()).cloned().collect();
+        dictionary.sort();
+        dictionary.dedup();
+
+        Fuzzer {
+            program: program.to_vec(),
+            rng: Rng::new(config.seed),
+            config,
+            dictionary,
+            dir: None,
+            corpus: Vec::new(),
+            edges: HashSet::new(),
+            outputs: HashMap::new(),
+            crashes: Vec::new(),
+            executions: 0,
+        }
+    }
+
+    /// Keeps the corpus and crashes under `dir`, starting from any inputs saved there by earlier runs.
+    pub fn with_dir(mut self, dir: &Path) -> io::Result<Fuzzer> {
+        fs::create_dir_all(dir.join("corpus"))?;
+        fs::create_dir_all(dir.join("crashes"))?;
+
+        let mut saved = Vec::new();
+        for entry in fs::read_dir(dir.join("corpus"))? {
+            let path = entry?.path();
+            saved.push(read_input(&path)?);
+        }
+        saved.sort();
+
+        self.dir = Some(dir.to_path_buf());
+        for input in saved {
+            self.evaluate(input)?;
+        }
+        Ok(self)
+    }
+
+    pub fn run(&mut self, iterations: u64) -> io::Result<()> {
+        if self.executions == 0 {
+            self.evaluate(Vec::new())?;
+        }
+
+        for _ in 0..iterations {
+            let parent = if self.corpus.is_empty() {
+                Vec::new()
+            } else {
+                self.corpus[self.rng.below(self.corpus.len())].clone()
+            };
+            let child = self.mutate(parent);
+            self.evaluate(child)?;
+        }
+        Ok(())
+    }
+
+    pub fn corpus(&self) -> &[Vec<i64>] {
+        &self.corpus
+    }
+
+    pub fn edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn crashes(&self) -> &[Crash] {
+        &self.crashes
+    }
+
+    /// Output values ordered from least to most often seen, with how often and the first input producing each.
+    pub fn rare_outputs(&self) -> Vec<(i64, u64, &[i64])> {
+        let mut outputs: Vec<(i64, u64, &[i64])> = self.outputs.iter()
+            .map(|(val, (count, input))| (*val, *count, input.as_slice()))
+            .collect();
+        outputs.sort_by_key(|(val, count, _)| (*count, *val));
+        outputs
+    }
+
+    fn evaluate(&mut self, mut input: Vec<i64>) -> io::Result<()> {
+        let exec = execute(&self.program, &input, self.config.max_steps);
+        self.executions += 1;
+
+        // Anything past what the program read made no difference.
+        input.truncate(exec.consumed);
+
+        let mut interesting = false;
+        for edge in &exec.edges {
+            interesting |= self.edges.insert(*edge);
+        }
+        for val in &exec.outputs {
+            let seen = self.outputs.entry(*val).or_insert_with(|| {
+                interesting = true;
+                (0, input.clone())
+            });
+            seen.0 += 1;
+        }
+
+        if let Some((kind, pos)) = exec.crash_key() {
+            if !self.crashes.iter().any(|c| c.kind == kind && c.pos == pos) {
+                let minimized = self.minimize(&input);
+                self.save("crashes", &format!("{}-{}", kind, pos), &minimized)?;
+                self.crashes.push(Crash { kind, pos, status: exec.status, input: minimized });
+            }
+            return Ok(());
+        }
+
+        if interesting {
+            let mut hasher = DefaultHasher::new();
+            input.hash(&mut hasher);
+            self.save("corpus", &format!("{:016x}", hasher.finish()), &input)?;
+            self.corpus.push(input);
+        }
+        Ok(())
+    }
+
+    fn mutate(&mut self, mut input: Vec<i64>) -> Vec<i64> {
+        let rounds = 1 + self.rng.below(4);
+        for _ in 0..rounds {
+            let len = input.len();
+            match self.rng.below(7) {
+                0 if len > 0 => {
+                    let i = self.rng.below(len);
+                    input[i] = self.value();
+                }
+                1 => {
+                    let i = self.rng.below(len + 1);
+                    let val = self.value();
+                    input.insert(i, val);
+                }
+                2 if len > 0 => {
+                    input.remove(self.rng.below(len));
+                }
+                3 if len > 0 => {
+                    let i = self.rng.below(len);
+                    input[i] = input[i].wrapping_add(if self.rng.below(2) == 0 { 1 } else { -1 });
+                }
+                4 if len > 0 => {
+                    let start = self.rng.below(len);
+                    let end = min(len, start + 1 + self.rng.below(8));
+                    let chunk = input[start..end].to_vec();
+                    let at = self.rng.below(len + 1);
+                    input.splice(at..at, chunk);
+                }
+                5 if !self.corpus.is_empty() => {
+                    let other = &self.corpus[self.rng.below(self.corpus.len())];
+                    let from = self.rng.below(other.len() + 1);
+                    let tail = other[from..].to_vec();
+                    input.truncate(self.rng.below(len + 1));
+                    input.extend(tail);
+                }
+                6 if len > 0 => {
+                    input.truncate(self.rng.below(len));
+                }
+                _ => {
+                    let val = self.value();
+                    input.push(val);
+                }
+            }
+        }
+        input.truncate(self.config.max_len);
+        input
+    }
+
+    fn value(&mut self) -> i64 {
+        match self.rng.below(5) {
+            0 | 1 => self.dictionary[self.rng.below(self.dictionary.len())],
+            2 | 3 => self.rng.below(11) as i64 - 1,
+            _ => self.rng.next() as i64,
+        }
+    }
+
+    /// Shrinks a crashing input for as long as it still crashes the same way at the same place.
+    pub fn minimize(&self, input: &[i64]) -> Vec<i64> {
+        let exec = execute(&self.program, input, self.config.max_steps);
+        let key = match exec.crash_key() {
+            None => { return input.to_vec(); }
+            Some(key) => key,
+        };
+        let crashes = |candidate: &[i64]| {
+            execute(&self.program, candidate, self.config.max_steps).crash_key() == Some(key)
+        };
+
+        let mut best = input[..exec.consumed].to_vec();
+
+        // Remove chunks, halving their size each pass.
+        let mut chunk = best.len() / 2;
+        while chunk > 0 {
+            let mut i = 0;
+            while i < best.len() {
+                let mut candidate = best.clone();
+                candidate.drain(i..min(i + chunk, best.len()));
+                if crashes(&candidate) {
+                    best = candidate;
+                } else {
+                    i += chunk;
+                }
+            }
+            chunk /= 2;
+        }
+
+        // Then replace what's left with the simplest values that still work.
+        for i in 0..best.len() {
+            for simpler in &[0, 1, -1] {
+                if best[i] == *simpler {
+                    break;
+                }
+                let mut candidate = best.clone();
+                candidate[i] = *simpler;
+                if crashes(&candidate) {
+                    best = candidate;
+                    break;
+                }
+            }
+        }
+
+        best
+    }
+
+    fn save(&self, kind: &str, name: &str, input: &[i64]) -> io::Result<()> {
+        match &self.dir {
+            None => Ok(()),
+            Some(dir) => fs::write(dir.join(kind).join(name), format_input(input)),
+        }
+    }
+}
+
+pub fn format_input(input: &[i64]) -> String {
+    let vals: Vec<String> = input.iter().map(|x| x.to_string()).collect();
+    vals.join(",") + "\n"
+}
+
+fn read_input(path: &Path) -> io::Result<Vec<i64>> {
+    let contents = fs::read_to_string(path)?;
+    contents.trim()
+        .split(',')
+        .filter(|x| !x.is_empty())
+        .map(|x| x.trim().parse::<i64>())
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads inputs until one is 7, then runs into a bad opcode.
+    const SEVEN: [i64; 13] = [3, 20, 1008, 20, 7, 21, 1005, 21, 12, 1105, 1, 0, 42];
+
+    #[test]
+    fn minimizes_crashes() {
+        let fuzzer = Fuzzer::new(&SEVEN, Config::default());
+        let input = [5, 7, 3, 9];
+        assert_eq!(execute(&SEVEN, &input, 1000).status, MachineStatus::BadOpcode(42));
+        assert_eq!(fuzzer.minimize(&input), vec![7]);
+        assert_eq!(fuzzer.minimize(&[5, 3]), vec![5, 3]);
+    }
+
+    #[test]
+    fn finds_crashes() {
+        let mut fuzzer = Fuzzer::new(&SEVEN, Config { seed: 7, ..Config::default() });
+        fuzzer.run(500).unwrap();
+        assert_eq!(fuzzer.executions, 501);
+        assert!(fuzzer.edges() > 0);
+        let crash = &fuzzer.crashes()[0];
+        assert_eq!((crash.kind, crash.pos, crash.status), ("bad-opcode", 12, MachineStatus::BadOpcode(42)));
+        assert_eq!(crash.input, vec![7]);
+    }
+}
@@ -0,0 +1,361 @@
+use std::cmp::min;
+use std::collections::HashSet;
+use crate::session::{RecordedInput, Session};
+use crate::MachineStatus::{BadAddress, BadMode, BadOpcode, Blocked, Finished, Runnable, StepLimit};
+
+pub mod ascii;
+pub mod fuzz;
+pub mod loader;
+pub mod session;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MachineStatus {
+    Runnable,
+    Blocked,
+    Finished,
+    BadOpcode(i64),
+    BadMode(i64),
+    BadAddress(i64),
+    StepLimit,
+}
+
+impl MachineStatus {
+    /// True if the machine stopped because of a fault rather than halting or waiting for input.
+    pub fn is_fault(&self) -> bool {
+        match self {
+            BadOpcode(_) | BadMode(_) | BadAddress(_) | StepLimit => true,
+            Runnable | Blocked | Finished => false,
+        }
+    }
+}
+
+/// A write that landed on a cell the machine had already executed as part of an instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CodeWrite {
+    pub pc: usize,
+    pub addr: usize,
+    pub old: i64,
+    pub new: i64,
+}
+
+#[derive(Clone)]
+pub struct Machine {
+    pub mem: Vec<i64>,
+    pub pos: usize,
+    pub inputs: Vec<i64>,
+    pub outputs: Vec<i64>,
+    pub input_pos: usize,
+    pub output_pos: usize,
+    pub status: MachineStatus,
+    pub relative_base: i64,
+
+    /// Number of instructions executed so far.
+    pub steps: u64,
+    /// If set, the machine stops with `StepLimit` once `steps` reaches this value.
+    pub step_limit: Option<u64>,
+
+    coverage: Option<HashSet<(usize, usize)>>,
+    executed: Option<Vec<bool>>,
+    code_writes: Vec<CodeWrite>,
+    recording: Option<Session>,
+}
+
+fn width(opcode: i64) -> usize {
+    match opcode {
+        1 | 2 | 7 | 8 => 4,
+        5 | 6 => 3,
+        3 | 4 | 9 => 2,
+        _ => 1,
+    }
+}
+
+impl Machine {
+    pub fn new(mem: &[i64]) -> Machine {
+        let mut new_mem = mem.to_vec();
+        new_mem.resize(mem.len() + 1000, 0);
+
+        Machine {
+            mem: new_mem,
+            pos: 0,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            input_pos: 0,
+            output_pos: 0,
+            status: Runnable,
+            relative_base: 0,
+            steps: 0,
+            step_limit: None,
+            coverage: None,
+            executed: None,
+            code_writes: Vec::new(),
+            recording: None,
+        }
+    }
+
+    /// Starts recording every `(pc, next pc)` edge taken by the machine.
+    pub fn enable_coverage(&mut self) {
+        self.coverage = Some(HashSet::new());
+    }
+
+    /// The edges taken since coverage was enabled, or `None` if it never was.
+    pub fn coverage(&self) -> Option<&HashSet<(usize, usize)>> {
+        self.coverage.as_ref()
+    }
+
+    /// Starts remembering which cells have been executed, so later writes to them are caught.
+    pub fn enable_write_tracking(&mut self) {
+        self.executed = Some(vec![false; self.mem.len()]);
+    }
+
+    /// Writes to already-executed cells, in the order they happened.
+    pub fn code_writes(&self) -> &[CodeWrite] {
+        &self.code_writes
+    }
+
+    fn addr(&self, arg: usize) -> Result<usize, MachineStatus> {
+        let addressing: i64 = self.mem[self.pos] / 100;
+        let mode = (addressing / 10_i64.pow(arg as u32)) % 10;
+        let raw = self.pos + 1 + arg;
+        if raw >= self.mem.len() {
+            return Err(BadAddress(raw as i64));
+        }
+
+        let addr = match mode {
+            0 => self.mem[raw],
+            1 => raw as i64,
+            2 => self.relative_base.wrapping_add(self.mem[raw]),
+            _ => { return Err(BadMode(mode)); }
+        };
+        if addr < 0 || addr as usize >= self.mem.len() {
+            return Err(BadAddress(addr));
+        }
+        Ok(addr as usize)
+    }
+
+    fn arg(&self, arg: usize) -> Result<i64, MachineStatus> {
+        Ok(self.mem[self.addr(arg)?])
+    }
+
+    fn write(&mut self, arg: usize, val: i64) -> Result<(), MachineStatus> {
+        let addr = self.addr(arg)?;
+        if let Some(executed) = &self.executed {
+            if executed[addr] {
+                self.code_writes.push(CodeWrite { pc: self.pos, addr, old: self.mem[addr], new: val });
+            }
+        }
+        self.mem[addr] = val;
+        Ok(())
+    }
+
+    fn jump(&mut self, target: i64) -> Result<(), MachineStatus> {
+        if target < 0 {
+            return Err(BadAddress(target));
+        }
+        self.pos = target as usize;
+        Ok(())
+    }
+
+    fn step(&mut self) -> Result<(), MachineStatus> {
+        if let Some(limit) = self.step_limit {
+            if self.steps >= limit {
+                return Err(StepLimit);
+            }
+        }
+        if self.pos >= self.mem.len() {
+            return Err(BadAddress(self.pos as i64));
+        }
+
+        let opcode = self.mem[self.pos] % 100;
+
+        // Mark the whole instruction, operands included, before it can write to itself.
+        if let Some(executed) = &mut self.executed {
+            let end = min(self.pos + width(opcode), executed.len());
+            for cell in &mut executed[self.pos..end] {
+                *cell = true;
+            }
+        }
+
+        match opcode {
+            1 => {
+                let a = self.arg(0)?;
+                let b = self.arg(1)?;
+                self.write(2, a.wrapping_add(b))?;
+                self.pos += 4;
+            }
+            2 => {
+                let a = self.arg(0)?;
+                let b = self.arg(1)?;
+                self.write(2, a.wrapping_mul(b))?;
+                self.pos += 4;
+            }
+            3 => {
+                if self.input_pos < self.inputs.len() {
+                    let val = self.inputs[self.input_pos];
+                    self.write(0, val)?;
+                    if let Some(session) = &mut self.recording {
+                        session.inputs.push(RecordedInput { step: self.steps, value: val });
+                    }
+                    self.input_pos += 1;
+                    self.pos += 2;
+                } else {
+                    return Err(Blocked);
+                }
+            }
+            4 => {
+                let val = self.arg(0)?;
+                self.outputs.push(val);
+                self.pos += 2;
+            }
+            5 => {
+                let cond = self.arg(0)?;
+                let target = self.arg(1)?;
+                if cond != 0 {
+                    self.jump(target)?;
+                } else {
+                    self.pos += 3;
+                }
+            }
+            6 => {
+                let cond = self.arg(0)?;
+                let target = self.arg(1)?;
+                if cond == 0 {
+                    self.jump(target)?;
+                } else {
+                    self.pos += 3;
+                }
+            }
+            7 => {
+                let a = self.arg(0)?;
+                let b = self.arg(1)?;
+                let val = (a < b) as i64;
+                self.write(2, val)?;
+                self.pos += 4;
+            }
+            8 => {
+                let a = self.arg(0)?;
+                let b = self.arg(1)?;
+                let val = (a == b) as i64;
+                self.write(2, val)?;
+                self.pos += 4;
+            }
+            9 => {
+                let val = self.arg(0)?;
+                self.relative_base = self.relative_base.wrapping_add(val);
+                self.pos += 2;
+            }
+            99 => {
+                return Err(Finished);
+            }
+            _ => {
+                return Err(BadOpcode(opcode));
+            }
+        }
+
+        self.steps += 1;
+        Ok(())
+    }
+
+    pub fn run(&mut self) {
+        if self.status != Runnable && self.status != Blocked {
+            return;
+        }
+
+        loop {
+            let pos = self.pos;
+            if let Err(status) = self.step() {
+                self.status = status;
+                return;
+            }
+
+            if let Some(coverage) = &mut self.coverage {
+                coverage.insert((pos, self.pos));
+            }
+        }
+    }
+
+    pub fn easy_run(&mut self, inputs: &[i64]) -> &Vec<i64> {
+        self.add_inputs(inputs);
+        self.run();
+        &self.outputs
+    }
+
+    pub fn add_input(&mut self, input: i64) {
+        self.inputs.push(input);
+    }
+
+    pub fn add_inputs(&mut self, inputs: &[i64]) {
+        self.inputs.extend(inputs);
+    }
+
+    pub fn get_output(&mut self) -> Option<i64> {
+        if self.output_pos < self.outputs.len() {
+            let val = self.outputs[self.output_pos];
+            self.output_pos += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_status(&self) -> MachineStatus {
+        self.status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &[i64]) -> Machine {
+        let mut machine = Machine::new(program);
+        machine.run();
+        machine
+    }
+
+    #[test]
+    fn faults() {
+        assert_eq!(run(&[42]).status, BadOpcode(42));
+        assert_eq!(run(&[301, 0, 0, 0, 99]).status, BadMode(3));
+        assert_eq!(run(&[1, -1, 0, 0, 99]).status, BadAddress(-1));
+        assert_eq!(run(&[1105, 1, -5]).status, BadAddress(-5));
+
+        let mut machine = Machine::new(&[1105, 1, 0]);
+        machine.step_limit = Some(10);
+        machine.run();
+        assert_eq!(machine.status, StepLimit);
+        assert_eq!(machine.steps, 10);
+        assert!(machine.status.is_fault());
+        assert!(!run(&[99]).status.is_fault());
+    }
+
+    #[test]
+    fn records_coverage() {
+        let mut machine = Machine::new(&[1101, 1, 1, 7, 1105, 1, 8, 0, 99]);
+        assert!(machine.coverage().is_none());
+        machine.enable_coverage();
+        machine.run();
+        assert_eq!(machine.status, Finished);
+        let edges: HashSet<(usize, usize)> = [(0, 4), (4, 8)].iter().cloned().collect();
+        assert_eq!(machine.coverage(), Some(&edges));
+    }
+
+    #[test]
+    fn tracks_code_writes() {
+        // Writes to a data cell, then patches the halt into code it hasn't reached, then
+        // overwrites its own first instruction.
+        let program = [1101, 1, 1, 13, 1101, 0, 99, 12, 1101, 7, 0, 0, 0, 0];
+        assert!(run(&program).code_writes().is_empty());
+
+        let mut machine = Machine::new(&program);
+        machine.enable_write_tracking();
+        machine.run();
+        assert_eq!(machine.status, Finished);
+        assert_eq!(machine.code_writes(), &[CodeWrite { pc: 8, addr: 0, old: 1101, new: 7 }]);
+
+        // An instruction writing to its own operand counts too.
+        let mut machine = Machine::new(&[1101, 5, 6, 1, 99]);
+        machine.enable_write_tracking();
+        machine.run();
+        assert_eq!(machine.code_writes(), &[CodeWrite { pc: 0, addr: 1, old: 5, new: 11 }]);
+    }
+}
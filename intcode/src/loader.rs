@@ -0,0 +1,252 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const IMAGE_MAGIC: &[u8] = b"ICIM";
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.col, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    Parse(ParseError),
+    Image(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(e) => write!(f, "{}", e),
+            LoadError::Parse(e) => write!(f, "{}", e),
+            LoadError::Image(message) => write!(f, "bad program image: {}", message),
+        }
+    }
+}
+
+impl Error for LoadError {}
+
+impl From<io::Error> for LoadError {
+    fn from(e: io::Error) -> LoadError {
+        LoadError::Io(e)
+    }
+}
+
+impl From<ParseError> for LoadError {
+    fn from(e: ParseError) -> LoadError {
+        LoadError::Parse(e)
+    }
+}
+
+/// Loads a program from a text listing, a binary image, or a gzipped copy of either.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<i64>, LoadError> {
+    from_bytes(&fs::read(path)?)
+}
+
+pub fn from_bytes(bytes: &[u8]) -> Result<Vec<i64>, LoadError> {
+    if bytes.starts_with(GZIP_MAGIC) {
+        let mut decoded = Vec::new();
+        GzDecoder::new(bytes).read_to_end(&mut decoded)?;
+        from_bytes(&decoded)
+    } else if bytes.starts_with(IMAGE_MAGIC) {
+        read_image(&bytes[IMAGE_MAGIC.len()..])
+    } else {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|e| LoadError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        Ok(parse(text)?)
+    }
+}
+
+/// Parses a text listing. Values are separated by commas and/or whitespace, and may span
+/// several lines; `#` starts a comment that runs to the end of the line.
+pub fn parse(text: &str) -> Result<Vec<i64>, ParseError> {
+    let mut mem = Vec::new();
+    let mut after_value = false;
+
+    for (line_index, line) in text.lines().enumerate() {
+        let code = match line.find('#') {
+            Some(i) => &line[..i],
+            None => line,
+        };
+        let error = |start: usize, message: String| ParseError {
+            line: line_index + 1,
+            col: code[..start].chars().count() + 1,
+            message,
+        };
+
+        let mut chars = code.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+            if c == ',' {
+                if !after_value {
+                    return Err(error(start, "expected a value before ','".to_string()));
+                }
+                after_value = false;
+                continue;
+            }
+
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, next)) = chars.peek() {
+                if next == ',' || next.is_whitespace() {
+                    break;
+                }
+                end = i + next.len_utf8();
+                chars.next();
+            }
+
+            let token = &code[start..end];
+            match token.parse::<i64>() {
+                Ok(val) => mem.push(val),
+                Err(e) => { return Err(error(start, format!("invalid value `{}`: {}", token, e))); }
+            }
+            after_value = true;
+        }
+    }
+
+    if mem.is_empty() {
+        return Err(ParseError { line: 1, col: 1, message: "program is empty".to_string() });
+    }
+    Ok(mem)
+}
+
+/// Writes `mem` in the binary image format: the magic bytes, the number of values, then
+/// each value, all as zigzag-encoded LEB128 varints.
+pub fn write_image(mem: &[i64], mut out: impl Write) -> io::Result<()> {
+    let mut buf = IMAGE_MAGIC.to_vec();
+    write_varint(&mut buf, mem.len() as u64);
+    for val in mem {
+        write_varint(&mut buf, ((val << 1) ^ (val >> 63)) as u64);
+    }
+    out.write_all(&buf)
+}
+
+pub fn save_image(path: impl AsRef<Path>, mem: &[i64]) -> io::Result<()> {
+    write_image(mem, fs::File::create(path)?)
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut val: u64) {
+    while val >= 0x80 {
+        buf.push((val as u8) | 0x80);
+        val >>= 7;
+    }
+    buf.push(val as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, LoadError> {
+    let mut val: u64 = 0;
+    for shift in (0..64).step_by(7) {
+        let (byte, rest) = match bytes.split_first() {
+            Some(split) => split,
+            None => { return Err(LoadError::Image("truncated".to_string())); }
+        };
+        *bytes = rest;
+        val |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(val);
+        }
+    }
+    Err(LoadError::Image("varint too long".to_string()))
+}
+
+fn read_image(mut bytes: &[u8]) -> Result<Vec<i64>, LoadError> {
+    let len = read_varint(&mut bytes)?;
+    // Every value takes at least one byte, so don't trust a length the data can't back up.
+    if len > bytes.len() as u64 {
+        return Err(LoadError::Image(format!("expected {} values but only {} bytes follow", len, bytes.len())));
+    }
+
+    let mut mem = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        let raw = read_varint(&mut bytes)?;
+        mem.push((raw >> 1) as i64 ^ -((raw & 1) as i64));
+    }
+    if !bytes.is_empty() {
+        return Err(LoadError::Image(format!("{} trailing bytes", bytes.len())));
+    }
+    Ok(mem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    fn image(mem: &[i64]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_image(mem, &mut buf).unwrap();
+        buf
+    }
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn image_round_trip() {
+        let mem = [0, 1, -1, 63, -64, 64, 1105, -300_000, 1 << 40, i64::MAX, i64::MIN];
+        let bytes = image(&mem);
+        assert_eq!(&bytes[..4], b"ICIM");
+        assert_eq!(&bytes[4..8], &[11, 0, 2, 1]);
+        assert_eq!(from_bytes(&bytes).unwrap(), mem.to_vec());
+        assert_eq!(from_bytes(&gzip(&bytes)).unwrap(), mem.to_vec());
+        assert_eq!(from_bytes(&image(&[])).unwrap(), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn bad_images() {
+        let bytes = image(&[1, 2, 300]);
+        let error = |bytes: &[u8]| from_bytes(bytes).unwrap_err().to_string();
+        assert_eq!(error(&bytes[..bytes.len() - 1]), "bad program image: truncated");
+        assert_eq!(error(&bytes[..5]), "bad program image: expected 3 values but only 0 bytes follow");
+        assert_eq!(error(&[&bytes[..], &[0]].concat()), "bad program image: 1 trailing bytes");
+        assert_eq!(error(&[b"ICIM\x01", &[0xff; 10][..], &[0]].concat()), "bad program image: varint too long");
+
+        // Without the magic the bytes are taken as a listing.
+        assert_eq!(error(b"ICIX\x03\x02\x04"), "line 1, column 1: invalid value `ICIX\x03\x02\x04`: invalid digit found in string");
+        assert!(matches!(from_bytes(&[0x80, 0x01]), Err(LoadError::Io(_))));
+    }
+
+    #[test]
+    fn listings() {
+        let text = "# header\n1,0,0, 3  # add\n\n  2,3,\n11,0\n99";
+        assert_eq!(parse(text).unwrap(), vec![1, 0, 0, 3, 2, 3, 11, 0, 99]);
+        assert_eq!(parse("1 2\t-3\r\n").unwrap(), vec![1, 2, -3]);
+        assert_eq!(from_bytes(&gzip(text.as_bytes())).unwrap(), parse(text).unwrap());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |text: &str| {
+            let e = parse(text).unwrap_err();
+            (e.line, e.col)
+        };
+        assert_eq!(error("1,2,3\n4,5x,6\n99"), (2, 3));
+        assert_eq!(parse("1,2,3\n4,5x,6\n99").unwrap_err().message, "invalid value `5x`: invalid digit found in string");
+        assert_eq!(error("1,2\n# é,\n  é1,99"), (3, 3));
+        assert_eq!(error("1,,2"), (1, 3));
+        assert_eq!(error("\n,1"), (2, 1));
+        assert_eq!(parse("# nothing\n").unwrap_err().message, "program is empty");
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::loader::{LoadError, ParseError};
+use crate::{Machine, MachineStatus};
+
+/// An input value and the instruction count at which the machine consumed it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordedInput {
+    pub step: u64,
+    pub value: i64,
+}
+
+/// Every input a machine consumed, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Session {
+    pub inputs: Vec<RecordedInput>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Divergence {
+    /// The machine asked for input `index` after a different number of instructions than recorded.
+    Step { index: usize, expected: u64, actual: u64 },
+    /// The machine stopped before consuming input `index`.
+    Stopped { index: usize, status: MachineStatus },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Divergence::Step { index, expected, actual } =>
+                write!(f, "input {} was read at step {}, recorded at step {}", index, actual, expected),
+            Divergence::Stopped { index, status } =>
+                write!(f, "machine stopped ({:?}) before reading input {}", status, index),
+        }
+    }
+}
+
+impl Machine {
+    /// Starts recording every input the machine consumes.
+    pub fn record_inputs(&mut self) {
+        self.recording = Some(Session::default());
+    }
+
+    /// The inputs recorded since `record_inputs`, or `None` if recording was never started.
+    pub fn session(&self) -> Option<&Session> {
+        self.recording.as_ref()
+    }
+}
+
+impl Session {
+    /// Feeds the recorded inputs to `machine` one at a time as it blocks for them, checking
+    /// that it asks at the same points it did when recorded.
+    pub fn replay(&self, machine: &mut Machine) -> Result<(), Divergence> {
+        for (index, input) in self.inputs.iter().enumerate() {
+            machine.run();
+            if machine.status != MachineStatus::Blocked {
+                return Err(Divergence::Stopped { index, status: machine.status });
+            }
+            if machine.steps != input.step {
+                return Err(Divergence::Step { index, expected: input.step, actual: machine.steps });
+            }
+            machine.add_input(input.value);
+        }
+        machine.run();
+        Ok(())
+    }
+
+    /// Index of the first input where the two sessions differ, if any.
+    pub fn first_difference(&self, other: &Session) -> Option<usize> {
+        let common = self.inputs.iter().zip(other.inputs.iter()).position(|(a, b)| a != b);
+        match common {
+            Some(index) => Some(index),
+            None if self.inputs.len() != other.inputs.len() => Some(self.inputs.len().min(other.inputs.len())),
+            None => None,
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut contents = String::from("# intcode session: <step> <value> per input\n");
+        for input in &self.inputs {
+            contents += &format!("{} {}\n", input.step, input.value);
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Session, LoadError> {
+        Ok(Session::parse(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Session, ParseError> {
+        let mut inputs = Vec::new();
+        for (line_index, line) in text.lines().enumerate() {
+            let code = match line.find('#') {
+                Some(i) => &line[..i],
+                None => line,
+            };
+            if code.trim().is_empty() {
+                continue;
+            }
+
+            let error = |message: String| ParseError { line: line_index + 1, col: 1, message };
+            let fields: Vec<&str> = code.split_whitespace().collect();
+            if fields.len() != 2 {
+                return Err(error(format!("expected `<step> <value>`, found `{}`", code.trim())));
+            }
+            let step = fields[0].parse::<u64>().map_err(|e| error(format!("invalid step `{}`: {}", fields[0], e)))?;
+            let value = fields[1].parse::<i64>().map_err(|e| error(format!("invalid value `{}`: {}", fields[1], e)))?;
+            inputs.push(RecordedInput { step, value });
+        }
+        Ok(Session { inputs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Outputs double each input until it reads a zero.
+    const DOUBLER: [i64; 15] = [3, 20, 1006, 20, 14, 102, 2, 20, 21, 4, 21, 1105, 1, 0, 99];
+
+    fn record(inputs: &[i64]) -> Session {
+        let mut machine = Machine::new(&DOUBLER);
+        machine.record_inputs();
+        machine.add_inputs(inputs);
+        machine.run();
+        assert_eq!(machine.status, MachineStatus::Finished);
+        machine.session().unwrap().clone()
+    }
+
+    #[test]
+    fn round_trip() {
+        let session = record(&[3, -5, 0]);
+        let steps: Vec<u64> = session.inputs.iter().map(|x| x.step).collect();
+        assert_eq!(steps, vec![0, 5, 10]);
+
+        let path = std::env::temp_dir().join(format!("intcode-session-{}.txt", std::process::id()));
+        session.save(&path).unwrap();
+        let loaded = Session::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, session);
+
+        let mut machine = Machine::new(&DOUBLER);
+        loaded.replay(&mut machine).unwrap();
+        assert_eq!(machine.status, MachineStatus::Finished);
+        assert_eq!(machine.outputs, vec![6, -10]);
+    }
+
+    #[test]
+    fn divergence() {
+        let session = Session::parse("# edited\n0 3\n6 5\n").unwrap();
+        let error = session.replay(&mut Machine::new(&DOUBLER)).unwrap_err();
+        assert_eq!(error, Divergence::Step { index: 1, expected: 6, actual: 5 });
+        assert_eq!(error.to_string(), "input 1 was read at step 5, recorded at step 6");
+
+        let session = Session::parse("0 0\n5 1\n").unwrap();
+        let error = session.replay(&mut Machine::new(&DOUBLER)).unwrap_err();
+        assert_eq!(error, Divergence::Stopped { index: 1, status: MachineStatus::Finished });
+
+        let a = record(&[1, 2, 0]);
+        assert_eq!(a.first_difference(&a), None);
+        assert_eq!(a.first_difference(&record(&[1, 3, 0])), Some(1));
+        assert_eq!(a.first_difference(&record(&[1, 2, 4, 0])), Some(2));
+        assert_eq!(record(&[0]).first_difference(&a), Some(0));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Session::parse("0 1\n\n5 2 3\n").unwrap_err();
+        assert_eq!((error.line, error.message.as_str()), (3, "expected `<step> <value>`, found `5 2 3`"));
+        assert_eq!(Session::parse("x 1").unwrap_err().message, "invalid step `x`: invalid digit found in string");
+    }
+}